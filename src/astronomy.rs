@@ -0,0 +1,211 @@
+/**************************************************************************
+ *
+ * These are the externally visible components of this file:
+ *
+ *     pub fn autumn_equinox(year: i32) -> f64
+ *
+ * Compute the instant of the autumnal (September) equinox of the given
+ * Gregorian year, as a Julian date in universal time.  A Julian date is
+ * the SDN plus the fraction of the day elapsed since noon, so the day of
+ * the equinox in Greenwich is (jd + 0.5).floor().
+ *
+ *     pub fn delta_t(year: f64) -> f64
+ *
+ * Compute the difference in seconds between dynamical time (TD) and
+ * universal time (UT) for the given decimal year.
+ *
+ *     pub fn equation_of_time(jd: f64) -> f64
+ *
+ * Compute the difference, in days, between apparent and mean solar time
+ * at the given Julian date.  It is positive when a sundial is ahead of a
+ * clock.
+ *
+ *     pub fn paris_equinox_sdn(year: i32) -> i32
+ *
+ * Compute the SDN of the day on which the autumnal equinox of the given
+ * Gregorian year falls at the Paris Observatory, days beginning at true
+ * (apparent) midnight.  This is the first day of the French republican
+ * year (year - 1791) as defined by the decree of 1793.
+ *
+ * VALID RANGE
+ *
+ *     The equinox is computed for the years -1000 to 3000, with an error of
+ *     about a minute.  Outside of this range the error grows quickly and
+ *     the results are only indicative.  The uncertainty on the future
+ *     values of delta T is anyway larger than a minute after 2100.
+ *
+ * ALGORITHMS
+ *
+ *     The mean equinox is given by a polynomial in the year, corrected by
+ *     the 24 main periodic terms of the motion of the Earth (which stands
+ *     for a truncated VSOP87 solar longitude).  Delta T uses the
+ *     polynomial expressions of Espenak and Meeus, and the equation of time
+ *     the expression of Smart.
+ *
+ * REFERENCES
+ *
+ *     Astronomical Algorithms by Jean Meeus, second edition, 1998,
+ *     chapters 27 (Equinoxes and Solstices) and 28 (Equation of Time).
+ *
+ *     Five Millennium Canon of Solar Eclipses by Fred Espenak and Jean
+ *     Meeus, NASA, 2006.
+ *
+ **************************************************************************/
+
+use core::f64::consts::PI;
+
+use libm::{cos, floor, sin, tan};
+
+const J2000: f64 = 2451545.0;
+const DAYS_PER_CENTURY: f64 = 36525.0;
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+/* Longitude of the Paris Observatory (2°20'14.025" east) in days. */
+const PARIS_LONGITUDE: f64 = (2.0 + 20.0 / 60.0 + 14.025 / 3600.0) / 360.0;
+
+/* Periodic terms A, B, C of the correction to the mean equinox. */
+const PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1934.136),
+    (203.0, 337.23, 32964.467),
+    (199.0, 342.08, 20.186),
+    (182.0, 27.85, 445267.112),
+    (156.0, 73.14, 45036.886),
+    (136.0, 171.52, 22518.443),
+    (77.0, 222.54, 65928.934),
+    (74.0, 296.72, 3034.906),
+    (70.0, 243.58, 9037.513),
+    (58.0, 119.81, 33718.147),
+    (52.0, 297.17, 150.678),
+    (50.0, 21.02, 2281.226),
+    (45.0, 247.54, 29929.562),
+    (44.0, 325.15, 31555.956),
+    (29.0, 60.93, 4443.417),
+    (18.0, 155.12, 67555.328),
+    (17.0, 288.79, 4562.452),
+    (16.0, 198.04, 62894.029),
+    (14.0, 199.76, 31436.921),
+    (12.0, 95.39, 14577.848),
+    (12.0, 287.11, 31931.756),
+    (12.0, 320.81, 34777.259),
+    (9.0, 227.73, 1222.114),
+    (8.0, 15.45, 16859.074),
+];
+
+fn polynomial(x: f64, coefficients: &[f64]) -> f64 {
+    coefficients.iter().rev().fold(0.0, |acc, &c| acc * x + c)
+}
+
+/* Julian ephemeris date (dynamical time) of the September equinox. */
+fn autumn_equinox_jde(year: i32) -> f64 {
+    let jde0 = if year < 1000 {
+        polynomial(
+            year as f64 / 1000.0,
+            &[1721325.70455, 365242.49558, -0.11677, -0.00297, 0.00074],
+        )
+    } else {
+        polynomial(
+            (year - 2000) as f64 / 1000.0,
+            &[2451810.21715, 365242.01767, -0.11575, 0.00337, 0.00078],
+        )
+    };
+
+    let t = (jde0 - J2000) / DAYS_PER_CENTURY;
+    let w = (35999.373 * t - 2.47).to_radians();
+    let delta_lambda = 1.0 + 0.0334 * cos(w) + 0.0007 * cos(2.0 * w);
+    let s: f64 = PERIODIC_TERMS
+        .iter()
+        .map(|&(a, b, c)| a * cos((b + c * t).to_radians()))
+        .sum();
+
+    jde0 + 0.00001 * s / delta_lambda
+}
+
+pub fn autumn_equinox(year: i32) -> f64 {
+    let jde = autumn_equinox_jde(year);
+    jde - delta_t(year as f64 + 0.72) / SECONDS_PER_DAY
+}
+
+pub fn delta_t(year: f64) -> f64 {
+    let long_term = |y: f64| {
+        let u = (y - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u
+    };
+
+    if year < -500.0 {
+        long_term(year)
+    } else if year < 500.0 {
+        polynomial(
+            year / 100.0,
+            &[10583.6, -1014.41, 33.78311, -5.952053, -0.1798452, 0.022174192, 0.0090316521],
+        )
+    } else if year < 1600.0 {
+        polynomial(
+            (year - 1000.0) / 100.0,
+            &[1574.2, -556.01, 71.23472, 0.319781, -0.8503463, -0.005050998, 0.0083572073],
+        )
+    } else if year < 1700.0 {
+        polynomial(year - 1600.0, &[120.0, -0.9808, -0.01532, 1.0 / 7129.0])
+    } else if year < 1800.0 {
+        polynomial(
+            year - 1700.0,
+            &[8.83, 0.1603, -0.0059285, 0.00013336, -1.0 / 1174000.0],
+        )
+    } else if year < 1860.0 {
+        polynomial(
+            year - 1800.0,
+            &[
+                13.72, -0.332447, 0.0068612, 0.0041116, -0.00037436, 0.0000121272,
+                -0.0000001699, 0.000000000875,
+            ],
+        )
+    } else if year < 1900.0 {
+        polynomial(
+            year - 1860.0,
+            &[7.62, 0.5737, -0.251754, 0.01680668, -0.0004473624, 1.0 / 233174.0],
+        )
+    } else if year < 1920.0 {
+        polynomial(
+            year - 1900.0,
+            &[-2.79, 1.494119, -0.0598939, 0.0061966, -0.000197],
+        )
+    } else if year < 1941.0 {
+        polynomial(year - 1920.0, &[21.20, 0.84493, -0.076100, 0.0020936])
+    } else if year < 1961.0 {
+        polynomial(year - 1950.0, &[29.07, 0.407, -1.0 / 233.0, 1.0 / 2547.0])
+    } else if year < 1986.0 {
+        polynomial(year - 1975.0, &[45.45, 1.067, -1.0 / 260.0, -1.0 / 718.0])
+    } else if year < 2005.0 {
+        polynomial(
+            year - 2000.0,
+            &[63.86, 0.3345, -0.060374, 0.0017275, 0.000651814, 0.00002373599],
+        )
+    } else if year < 2050.0 {
+        polynomial(year - 2000.0, &[62.92, 0.32217, 0.005589])
+    } else if year < 2150.0 {
+        long_term(year) - 0.5628 * (2150.0 - year)
+    } else {
+        long_term(year)
+    }
+}
+
+pub fn equation_of_time(jd: f64) -> f64 {
+    let t = (jd - J2000) / DAYS_PER_CENTURY;
+    let epsilon = polynomial(t, &[23.4392911, -0.0130042, -0.00000016, 0.000000504]).to_radians();
+    let l0 = polynomial(t, &[280.46646, 36000.76983, 0.0003032]).to_radians();
+    let m = polynomial(t, &[357.52911, 35999.05029, -0.0001537]).to_radians();
+    let e = polynomial(t, &[0.016708634, -0.000042037, -0.0000001267]);
+    let y = tan(epsilon / 2.0) * tan(epsilon / 2.0);
+
+    let equation = y * sin(2.0 * l0) - 2.0 * e * sin(m)
+        + 4.0 * e * y * sin(m) * cos(2.0 * l0)
+        - 0.5 * y * y * sin(4.0 * l0)
+        - 1.25 * e * e * sin(2.0 * m);
+
+    equation / (2.0 * PI)
+}
+
+pub fn paris_equinox_sdn(year: i32) -> i32 {
+    let jd = autumn_equinox(year);
+    let apparent_jd = jd + PARIS_LONGITUDE + equation_of_time(jd);
+    floor(apparent_jd + 0.5) as i32
+}
@@ -0,0 +1,295 @@
+/**************************************************************************
+ *
+ * repub, a command line converter between the Gregorian and the French
+ * republican calendars:
+ *
+ *     repub to-french [--format FORMAT] [--json] [DATE...]
+ *
+ * Convert Gregorian dates written as YYYY-MM-DD, e.g. 1799-11-09, to the
+ * French republican calendar.  They are displayed as "Octidi 18 Brumaire
+ * an VIII" by default, which to-gregorian and info read back.
+ *
+ *     repub to-gregorian [--format FORMAT] [--json] [DATE...]
+ *
+ * Convert French republican dates written in words, e.g. "18 brumaire
+ * VIII" (see parse.rs for the accepted forms), to the Gregorian calendar.
+ * They are displayed as YYYY-MM-DD by default.  The default outputs of
+ * to-french and to-gregorian can be converted back with each other, e.g.
+ * repub to-french 1799-11-09 | repub to-gregorian.
+ *
+ *     repub info [--json] [DATE...]
+ *
+ * Show a date, written in either calendar, in both calendars, with its
+ * day of the week, its day of the décade, its name in the rural calendar
+ * and its SDN.
+ *
+ * --format gives the format of the converted dates, with the directives
+ * of format.rs, e.g. --format "%d %B an %Y".  --json writes a JSON object
+ * per date and per line instead, with the fields of the date and the
+ * formatted date, or an "error" field for a date that cannot be converted.
+ * Without a date on the command line, the dates are read from the
+ * standard input, one per line, and the empty lines are skipped.  Each
+ * date is converted and written as soon as its line is read.
+ *
+ * The exit code tells whether all the dates were converted:
+ *
+ *     0    success.
+ *     1    a date is malformed or does not exist, e.g. 1900-02-29.
+ *     2    the command line is wrong.
+ *     3    a date is outside of the range of the calendars, e.g. a
+ *          Gregorian date before the republican era.
+ *     4    the standard input cannot be read, e.g. it is not UTF-8, or
+ *          the standard output cannot be written.
+ *
+ * If several dates fail, the exit code is the one of the first failure,
+ * except for an error of input or output, which stops repub at once.
+ * The error messages are written to the standard error, or as the "error"
+ * field with --json.
+ *
+ **************************************************************************/
+
+use std::fmt::Write as _;
+use std::io::{self, BufRead, Write as _};
+use std::process::ExitCode;
+
+use french_republican_calendar::french_calendar::FrenchDate;
+use french_republican_calendar::gregorian_calendar::GregorianDate;
+use french_republican_calendar::literal::{parse_gregorian, LiteralError};
+use french_republican_calendar::parse::{parse_republican, ParseErrorKind};
+use french_republican_calendar::{convert, CalendarDate, CalendarError};
+
+const USAGE: &str = "\
+usage: repub to-french [--format FORMAT] [--json] [DATE...]
+       repub to-gregorian [--format FORMAT] [--json] [DATE...]
+       repub info [--json] [DATE...]
+
+Without a DATE, the dates are read from the standard input, one per line.";
+
+const INVALID_DATE: u8 = 1;
+const USAGE_ERROR: u8 = 2;
+const OUT_OF_RANGE: u8 = 3;
+const IO_ERROR: u8 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    ToFrench,
+    ToGregorian,
+    Info,
+}
+
+struct Options {
+    command: Command,
+    format: Option<String>,
+    json: bool,
+    dates: Vec<String>,
+}
+
+/* A date that cannot be converted: the exit code and the message. */
+struct Failure(u8, String);
+
+impl From<CalendarError> for Failure {
+    fn from(error: CalendarError) -> Failure {
+        let code = if error == CalendarError::OutOfRange {OUT_OF_RANGE} else {INVALID_DATE};
+        Failure(code, error.to_string())
+    }
+}
+
+fn parse_options(args: &[String]) -> Result<Options, String> {
+    let command = match args.first().map(String::as_str) {
+        Some("to-french") => Command::ToFrench,
+        Some("to-gregorian") => Command::ToGregorian,
+        Some("info") => Command::Info,
+        Some(other) => return Err(format!("unknown command {:?}", other)),
+        None => return Err("missing command".to_string()),
+    };
+    let mut options = Options {command, format: None, json: false, dates: Vec::new()};
+    let mut rest = args[1..].iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--json" => options.json = true,
+            "--format" => {
+                let format = rest.next().ok_or("missing format after --format")?;
+                options.format = Some(format.clone());
+            }
+            "--" => options.dates.extend(rest.by_ref().cloned()),
+            _ if arg.starts_with("--format=") => options.format = Some(arg["--format=".len()..].to_string()),
+            _ if arg.starts_with('-') && arg.len() > 1 && !arg[1..].starts_with(|c: char| c.is_ascii_digit()) => {
+                return Err(format!("unknown option {:?}", arg));
+            }
+            _ => options.dates.push(arg.clone()),
+        }
+    }
+    if command == Command::Info && options.format.is_some() {
+        return Err("--format is not supported by info".to_string());
+    }
+    Ok(options)
+}
+
+fn read_gregorian(text: &str) -> Result<GregorianDate, Failure> {
+    parse_gregorian(text.trim()).map_err(|error| {
+        let code = match error {
+            LiteralError::Date(CalendarError::OutOfRange) => OUT_OF_RANGE,
+            _ => INVALID_DATE,
+        };
+        Failure(code, error.to_string())
+    })
+}
+
+fn read_french(text: &str) -> Result<FrenchDate, Failure> {
+    parse_republican(text).map_err(|error| {
+        let code = match error.kind() {
+            ParseErrorKind::Date(CalendarError::OutOfRange) => OUT_OF_RANGE,
+            _ => INVALID_DATE,
+        };
+        Failure(code, error.to_string())
+    })
+}
+
+/* Read a date written in either calendar, as the dates in words cannot be
+ * Gregorian. */
+fn read_any(text: &str) -> Result<(GregorianDate, FrenchDate), Failure> {
+    let starts_like_iso = text.trim().starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '+')
+        && text.trim().bytes().filter(|&b| b == b'-').count() >= 2;
+    if starts_like_iso {
+        let gregorian_date = read_gregorian(text)?;
+        Ok((gregorian_date, convert(&gregorian_date)?))
+    } else {
+        let french_date = read_french(text)?;
+        Ok((convert(&french_date)?, french_date))
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut json = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn json_date<T: CalendarDate>(date: &T) -> String {
+    format!("{{\"year\": {}, \"month\": {}, \"day\": {}}}", date.year(), date.month(), date.day())
+}
+
+/* The output of a date, in text or in JSON. */
+fn convert_date(options: &Options, text: &str) -> Result<String, Failure> {
+    match options.command {
+        Command::ToFrench => {
+            let french_date: FrenchDate = convert(&read_gregorian(text)?)?;
+            let formatted = match &options.format {
+                Some(format) => french_date.format(format).to_string(),
+                None => french_date.to_string(),
+            };
+            Ok(if options.json {
+                format!("{{\"input\": {}, \"french\": {}, \"text\": {}}}",
+                        json_string(text), json_date(&french_date), json_string(&formatted))
+            } else {
+                formatted
+            })
+        }
+        Command::ToGregorian => {
+            let gregorian_date: GregorianDate = convert(&read_french(text)?)?;
+            let format = options.format.as_deref().unwrap_or("%Y-%m-%d");
+            let formatted = gregorian_date.format(format).to_string();
+            Ok(if options.json {
+                format!("{{\"input\": {}, \"gregorian\": {}, \"text\": {}}}",
+                        json_string(text), json_date(&gregorian_date), json_string(&formatted))
+            } else {
+                formatted
+            })
+        }
+        Command::Info => {
+            let (gregorian_date, french_date) = read_any(text)?;
+            let weekday = gregorian_date.format("%A").to_string();
+            let decade_day = (french_date.month() != 13).then(|| french_date.format("%A").to_string());
+            let rural_name = french_date.format("%R").to_string();
+            let sdn = gregorian_date.to_sdn()?.value();
+            Ok(if options.json {
+                format!("{{\"input\": {}, \"gregorian\": {}, \"french\": {}, \"weekday\": {}, \
+                         \"decade_day\": {}, \"rural_name\": {}, \"sdn\": {}}}",
+                        json_string(text), json_date(&gregorian_date), json_date(&french_date),
+                        json_string(&weekday), decade_day.as_deref().map_or("null".to_string(), json_string),
+                        json_string(&rural_name), sdn)
+            } else {
+                format!("Gregorian:   {}\nRepublican:  {}\nWeekday:     {}\nDécade day:  {}\nRural name:  {}\nSDN:         {}",
+                        gregorian_date, french_date, weekday, decade_day.as_deref().unwrap_or("-"),
+                        rural_name, sdn)
+            })
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+    let options = match parse_options(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("repub: {}\n{}", message, USAGE);
+            return ExitCode::from(USAGE_ERROR);
+        }
+    };
+
+    /* The dates of the standard input are converted as they are read, so
+     * that repub can be used in a pipeline. */
+    let mut stdout = io::stdout().lock();
+    let mut exit_code = 0;
+    let mut count = 0;
+    let mut stdin_lines = if options.dates.is_empty() {Some(io::stdin().lock().lines())} else {None};
+    let mut arg_dates = options.dates.iter();
+    loop {
+        let text = match stdin_lines.as_mut() {
+            Some(lines) => match lines.next() {
+                Some(Ok(line)) if line.trim().is_empty() => continue,
+                Some(Ok(line)) => line,
+                Some(Err(error)) => {
+                    eprintln!("repub: cannot read the standard input: {}", error);
+                    return ExitCode::from(IO_ERROR);
+                }
+                None => break,
+            },
+            None => match arg_dates.next() {
+                Some(text) => text.clone(),
+                None => break,
+            },
+        };
+
+        let written = match convert_date(&options, &text) {
+            Ok(output) => {
+                if options.command == Command::Info && !options.json && count > 0 {
+                    writeln!(stdout).and_then(|()| writeln!(stdout, "{}", output))
+                } else {
+                    writeln!(stdout, "{}", output)
+                }
+            }
+            Err(Failure(code, message)) => {
+                if exit_code == 0 {
+                    exit_code = code;
+                }
+                if options.json {
+                    writeln!(stdout, "{{\"input\": {}, \"error\": {}}}", json_string(&text), json_string(&message))
+                } else {
+                    eprintln!("repub: {:?}: {}", text, message);
+                    Ok(())
+                }
+            }
+        };
+        if let Err(error) = written.and_then(|()| stdout.flush()) {
+            eprintln!("repub: cannot write the standard output: {}", error);
+            return ExitCode::from(IO_ERROR);
+        }
+        count += 1;
+    }
+    ExitCode::from(exit_code)
+}
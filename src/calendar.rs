@@ -0,0 +1,310 @@
+/**************************************************************************
+ *
+ * These are the externally visible components of this file:
+ *
+ *     pub trait CalendarDate
+ *
+ * The operations shared by the dates of every calendar of this package,
+ * so that generic code can handle any of them.  It is implemented by
+ * FrenchDate (with the default leap rule), GregorianDate, JulianDate and
+ * JewishDate:
+ *
+ *     fn from_ymd(year: i32, month: i32, day: i32) -> Result<Self, CalendarError>
+ *     fn year(&self) -> i32
+ *     fn month(&self) -> i32
+ *     fn day(&self) -> i32
+ *
+ * Create a checked date and read its fields, as the new(), year(),
+ * month() and day() methods of each date.
+ *
+ *     fn to_sdn(&self) -> Result<Sdn, CalendarError>
+ *     fn from_sdn(sdn: Sdn) -> Result<Self, CalendarError>
+ *
+ * Convert the date to and from a SDN, as the try_<CALENDAR>_to_sdn() and
+ * try_sdn_to_<CALENDAR>() routines.
+ *
+ *     fn valid_range() -> RangeInclusive<Sdn>
+ *
+ * Return the range of the SDNs that can be converted to a date of the
+ * calendar.
+ *
+ *     fn is_leap_year(year: i32) -> bool
+ *
+ * Tell whether the year is longer than a common year: a leap year of the
+ * Gregorian, Julian or Jewish calendar or a sextile year of the French
+ * republican calendar.
+ *
+ *     fn days_in_month(year: i32, month: i32) -> i32
+ *
+ * Return the number of days of a month, or zero if the month does not
+ * exist in the year.  The complementary days of the French republican
+ * calendar are month 13.
+ *
+ *     fn month_name(year: i32, month: i32) -> &'static str
+ *
+ * Return the name of a month, or a zero length string if the month does
+ * not exist in the year.
+ *
+ *     fn next_day(&mut self)
+ *
+ * Move the date to the following day.
+ *
+ *     fn months_before_year(year: i32) -> i64
+ *
+ * Return the number of months from the start of year 1 to the start of
+ * the year, which is negative for the years before year 1.  The trait
+ * provides it for the calendars whose years all have the same number of
+ * months, and the Jewish calendar computes it from its 19 year cycle.
+ *
+ * The trait also provides the date arithmetic:
+ *
+ *     fn prev_day(&mut self)
+ *
+ * Move the date to the previous day.  It panics if the date is the first
+ * day of the valid range.
+ *
+ *     fn add_days(&self, days: Days) -> Result<Self, CalendarError>
+ *
+ * Return the date the given number of days (possibly negative) later, or
+ * OutOfRange if it is outside of the valid range.
+ *
+ *     fn try_days_until(&self, other: &Self) -> Result<Days, CalendarError>
+ *     fn days_until(&self, other: &Self) -> Days
+ *
+ * Return the number of days from the date to another date, which is
+ * negative if the other date is earlier.  try_days_until() returns the
+ * error of to_sdn() when one of the dates has no SDN, such as the zero
+ * date returned by sdn_to_<CALENDAR>() or a FrenchDate created with
+ * another leap rule that is invalid under the default one, and
+ * days_until() panics in this case.
+ *
+ *     fn add_months(&self, months: i32, policy: ClampPolicy) -> Result<Self, CalendarError>
+ *     fn add_years(&self, years: i32, policy: ClampPolicy) -> Result<Self, CalendarError>
+ *
+ * Return the same day the given number of months or years (possibly
+ * negative) later.  The months are the ones that exist in each year, so
+ * the complementary days are the 13th month of the French republican
+ * calendar and Adar II is skipped in a common Jewish year.  Year zero is
+ * skipped in the calendars counting years B.C. and A.D.  When the day
+ * does not exist in the target month, the policy tells what to do:
+ *
+ *     Clamp     use the last day of the month, e.g. January 31 plus one
+ *               month is February 28 (or 29), and the 6th complementary
+ *               day plus one year is the 5th complementary day.
+ *     Spill     carry the extra days over to the next month, e.g.
+ *               January 31 plus one month is March 3 (or 2), and the 6th
+ *               complementary day plus one year is the 1st Vendémiaire of
+ *               the following year.
+ *     Strict    return the error of the invalid date, InvalidDay or
+ *               NoSixthSansculottide.
+ *
+ * Adding years to Adar II gives Adar in a common Jewish year, except with
+ * the Strict policy which returns InvalidMonth.
+ *
+ *     pub struct Days(pub i32)
+ *
+ * A number of days.  The dates implement the operators:
+ *
+ *     date + Days -> date
+ *     date - Days -> date
+ *     date - date -> Days     (panics as days_until())
+ *     date += Days
+ *     date -= Days
+ *
+ * As for the integers, the operators panic when the result overflows, that
+ * is when it is outside of the valid range of the calendar.  Use
+ * add_days() to handle this case.  The dates also implement Ord, and they
+ * are sorted in chronological order.
+ *
+ *     pub fn convert<T: CalendarDate, U: CalendarDate>(date: &T) -> Result<U, CalendarError>
+ *
+ * Convert a date from one calendar to another through its SDN.  For
+ * example:
+ *
+ *    let gregorian_date = GregorianDate::new(1799, 11, 9)?;
+ *    let french_date: FrenchDate = convert(&gregorian_date)?;
+ *
+ **************************************************************************/
+
+use core::ops::RangeInclusive;
+
+use crate::error::CalendarError;
+use crate::sdn::Sdn;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Days(pub i32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ClampPolicy {
+    #[default]
+    Clamp,
+    Spill,
+    Strict,
+}
+
+pub trait CalendarDate: Sized {
+    fn from_ymd(year: i32, month: i32, day: i32) -> Result<Self, CalendarError>;
+
+    fn year(&self) -> i32;
+
+    fn month(&self) -> i32;
+
+    fn day(&self) -> i32;
+
+    fn to_sdn(&self) -> Result<Sdn, CalendarError>;
+
+    fn from_sdn(sdn: Sdn) -> Result<Self, CalendarError>;
+
+    fn valid_range() -> RangeInclusive<Sdn>;
+
+    fn is_leap_year(year: i32) -> bool;
+
+    fn days_in_month(year: i32, month: i32) -> i32;
+
+    fn month_name(year: i32, month: i32) -> &'static str;
+
+    fn next_day(&mut self);
+
+    fn months_before_year(year: i32) -> i64 {
+        months_of::<Self>(year).count() as i64 * (astronomical_year(year) - 1)
+    }
+
+    fn prev_day(&mut self) {
+        *self = self.add_days(Days(-1)).expect("date out of range");
+    }
+
+    fn add_days(&self, days: Days) -> Result<Self, CalendarError> {
+        Self::from_sdn(self.to_sdn()?.checked_add(days)?)
+    }
+
+    fn try_days_until(&self, other: &Self) -> Result<Days, CalendarError> {
+        Ok(self.to_sdn()?.days_until(other.to_sdn()?))
+    }
+
+    fn days_until(&self, other: &Self) -> Days {
+        match self.try_days_until(other) {
+            Ok(days) => days,
+            Err(error) => panic!("cannot count the days between the dates: {}", error),
+        }
+    }
+
+    fn add_months(&self, months: i32, policy: ClampPolicy) -> Result<Self, CalendarError> {
+        let target = month_count::<Self>(self.year(), self.month())? + months as i64;
+
+        /* The last year starting at or before the target month, found by
+         * bisection: as every year has a month, it is at most the number of
+         * months plus the 13 months of the year away. */
+        let span = (months as i64).abs() + 13;
+        let (mut low, mut high) = (-span, span);
+        while low < high {
+            let middle = low + (high - low + 1) / 2;
+            if Self::months_before_year(add_to_year(self.year(), middle)?) <= target {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+
+        let year = add_to_year(self.year(), low)?;
+        let index = target - Self::months_before_year(year);
+        let month = months_of::<Self>(year).nth(index as usize).ok_or(CalendarError::OutOfRange)?;
+        clamp_day(year, month, self.day(), policy)
+    }
+
+    fn add_years(&self, years: i32, policy: ClampPolicy) -> Result<Self, CalendarError> {
+        let year = add_to_year(self.year(), years as i64)?;
+        let mut month = self.month();
+        if Self::days_in_month(year, month) == 0 {
+            if policy == ClampPolicy::Strict {
+                return Err(CalendarError::InvalidMonth);
+            }
+            month -= 1;
+        }
+        clamp_day(year, month, self.day(), policy)
+    }
+}
+
+/* The year counting 1 B.C. as year 0, as the years are counted in the
+ * calendars that skip year zero. */
+pub(crate) fn astronomical_year(year: i32) -> i64 {
+    if year < 0 {year as i64 + 1} else {year as i64}
+}
+
+/* Add years to a year, skipping year zero. */
+fn add_to_year(year: i32, years: i64) -> Result<i32, CalendarError> {
+    let result = astronomical_year(year) + years;
+    i32::try_from(if result <= 0 {result - 1} else {result}).map_err(|_| CalendarError::OutOfRange)
+}
+
+/* The months that exist in the year, in order. */
+fn months_of<T: CalendarDate>(year: i32) -> impl Iterator<Item = i32> {
+    (1..=13).filter(move |&month| T::days_in_month(year, month) > 0)
+}
+
+/* The number of months from the start of year 1 to the month. */
+pub(crate) fn month_count<T: CalendarDate>(year: i32, month: i32) -> Result<i64, CalendarError> {
+    let index = months_of::<T>(year).position(|other| other == month).ok_or(CalendarError::InvalidMonth)?;
+    Ok(T::months_before_year(year) + index as i64)
+}
+
+/* Create the date, applying the policy if the day is after the end of the
+ * month. */
+fn clamp_day<T: CalendarDate>(year: i32, month: i32, day: i32, policy: ClampPolicy)
+    -> Result<T, CalendarError> {
+    let length = T::days_in_month(year, month);
+    if day <= length || policy == ClampPolicy::Strict {
+        T::from_ymd(year, month, day)
+    } else if policy == ClampPolicy::Clamp {
+        T::from_ymd(year, month, length)
+    } else {
+        T::from_ymd(year, month, length)?.add_days(Days(day - length))
+    }
+}
+
+pub fn convert<T: CalendarDate, U: CalendarDate>(date: &T) -> Result<U, CalendarError> {
+    U::from_sdn(date.to_sdn()?)
+}
+
+/* Implement the arithmetic operators on a type of date. */
+macro_rules! impl_date_arithmetic {
+    ($date:ty) => {
+        impl core::ops::Add<crate::calendar::Days> for $date {
+            type Output = $date;
+
+            fn add(self, days: crate::calendar::Days) -> $date {
+                crate::calendar::CalendarDate::add_days(&self, days).expect("date out of range")
+            }
+        }
+
+        impl core::ops::Sub<crate::calendar::Days> for $date {
+            type Output = $date;
+
+            fn sub(self, days: crate::calendar::Days) -> $date {
+                let days = crate::calendar::Days(days.0.checked_neg().expect("date out of range"));
+                crate::calendar::CalendarDate::add_days(&self, days).expect("date out of range")
+            }
+        }
+
+        impl core::ops::Sub for $date {
+            type Output = crate::calendar::Days;
+
+            fn sub(self, other: $date) -> crate::calendar::Days {
+                crate::calendar::CalendarDate::days_until(&other, &self)
+            }
+        }
+
+        impl core::ops::AddAssign<crate::calendar::Days> for $date {
+            fn add_assign(&mut self, days: crate::calendar::Days) {
+                *self = *self + days;
+            }
+        }
+
+        impl core::ops::SubAssign<crate::calendar::Days> for $date {
+            fn sub_assign(&mut self, days: crate::calendar::Days) {
+                *self = *self - days;
+            }
+        }
+    };
+}
+
+pub(crate) use impl_date_arithmetic;
@@ -0,0 +1,137 @@
+/**************************************************************************
+ *
+ * These are the externally visible components of this file:
+ *
+ *     pub fn FrenchDate::today(offset: UtcOffset) -> Result<FrenchDate, CalendarError>
+ *     pub fn GregorianDate::today(offset: UtcOffset) -> Result<GregorianDate, CalendarError>
+ *
+ * Give the current date at the given offset from UTC, read from the system
+ * clock.  They need the "std" feature.  The French republican date uses
+ * the default leap year rule, through try_sdn_to_french() rather than
+ * sdn_to_french(), which only converts the years 1 to 14.  An error is
+ * only returned if the clock is set to a date that the calendar cannot
+ * convert, e.g. before the republican era.  For example:
+ *
+ *    let french_date = FrenchDate::today(UtcOffset::PARIS_MEAN_TIME)?;
+ *
+ *     pub fn FrenchDate::today_with_clock<C: Clock>(clock: &C, offset: UtcOffset)
+ *         -> Result<FrenchDate, CalendarError>
+ *     pub fn GregorianDate::today_with_clock<C: Clock>(clock: &C, offset: UtcOffset)
+ *         -> Result<GregorianDate, CalendarError>
+ *
+ * Same as above, but the current time is read from the given clock, so
+ * that the tests do not depend on the day they are run.
+ *
+ *     pub trait Clock
+ *
+ * A source of the current time, as the seconds since January 1, 1970 at
+ * 00:00 UTC (see day_count::unix_to_sdn()).  It has a single method:
+ *
+ *     fn unix_seconds(&self) -> i64
+ *
+ *     pub struct SystemClock
+ *     pub struct FixedClock(pub i64)
+ *
+ * The clocks provided: the system clock, with the "std" feature, and a
+ * clock that is stopped at the given Unix time.
+ *
+ *     pub struct UtcOffset
+ *     pub const UtcOffset::UTC: UtcOffset
+ *     pub const UtcOffset::PARIS_MEAN_TIME: UtcOffset
+ *     pub fn UtcOffset::new(seconds: i32) -> Result<UtcOffset, CalendarError>
+ *     pub fn UtcOffset::seconds(self) -> i32
+ *
+ * The offset of a local time from UTC, in seconds, positive east of
+ * Greenwich, e.g. 3600 for the Central European Time.  It must be less
+ * than a day, otherwise OutOfRange is returned.  The Paris Mean Time,
+ * the mean solar time at the Paris Observatory, was the time of France
+ * under the Republic and the legal time until 1911.  It is 9 minutes 21
+ * seconds ahead of UTC.
+ *
+ **************************************************************************/
+
+use crate::calendar::CalendarDate;
+use crate::day_count::unix_to_sdn;
+use crate::error::CalendarError;
+use crate::french_calendar::FrenchDate;
+use crate::gregorian_calendar::GregorianDate;
+
+const SECONDS_PER_DAY: i32 = 86_400;
+
+pub trait Clock {
+    fn unix_seconds(&self) -> i64;
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn unix_seconds(&self) -> i64 {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(elapsed) => elapsed.as_secs() as i64,
+            Err(error) => -(error.duration().as_secs() as i64),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedClock(pub i64);
+
+impl Clock for FixedClock {
+    fn unix_seconds(&self) -> i64 {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct UtcOffset(i32);
+
+impl UtcOffset {
+    pub const UTC: UtcOffset = UtcOffset(0);
+    pub const PARIS_MEAN_TIME: UtcOffset = UtcOffset(9 * 60 + 21);
+
+    pub fn new(seconds: i32) -> Result<UtcOffset, CalendarError> {
+        if seconds <= -SECONDS_PER_DAY || seconds >= SECONDS_PER_DAY {
+            Err(CalendarError::OutOfRange)
+        } else {
+            Ok(UtcOffset(seconds))
+        }
+    }
+
+    pub fn seconds(self) -> i32 {
+        self.0
+    }
+}
+
+/* The current date in any calendar. */
+fn today<T: CalendarDate, C: Clock>(clock: &C, offset: UtcOffset) -> Result<T, CalendarError> {
+    let seconds = clock.unix_seconds().checked_add(offset.0 as i64).ok_or(CalendarError::OutOfRange)?;
+    let (sdn, _) = unix_to_sdn(seconds)?;
+    sdn.to_date()
+}
+
+impl FrenchDate {
+    #[cfg(feature = "std")]
+    pub fn today(offset: UtcOffset) -> Result<FrenchDate, CalendarError> {
+        today(&SystemClock, offset)
+    }
+
+    pub fn today_with_clock<C: Clock>(clock: &C, offset: UtcOffset) -> Result<FrenchDate, CalendarError> {
+        today(clock, offset)
+    }
+}
+
+impl GregorianDate {
+    #[cfg(feature = "std")]
+    pub fn today(offset: UtcOffset) -> Result<GregorianDate, CalendarError> {
+        today(&SystemClock, offset)
+    }
+
+    pub fn today_with_clock<C: Clock>(clock: &C, offset: UtcOffset) -> Result<GregorianDate, CalendarError> {
+        today(clock, offset)
+    }
+}
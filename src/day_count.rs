@@ -0,0 +1,153 @@
+/**************************************************************************
+ *
+ * These are the externally visible components of this file:
+ *
+ *     pub fn sdn_to_julian_day(sdn: Sdn, time: &StandardTime) -> f64
+ *     pub fn julian_day_to_sdn(julian_day: f64) -> Result<(Sdn, StandardTime), CalendarError>
+ *
+ * Convert a day and a time of day to a fractional Julian Date and back.
+ * The Julian Date counts the days from noon, so that the day whose SDN is
+ * N starts at midnight at the Julian Date N - 0.5 and the Julian Date N.0
+ * is its noon, e.g. 2451545.0 is January 1, 2000 at 12:00.  The time of
+ * day given by julian_day_to_sdn() is rounded to the millisecond, as an
+ * f64 holds a current Julian Date to about 40 microseconds.  OutOfRange
+ * is returned for a Julian Date that is not finite or outside of the
+ * range of Sdn.
+ *
+ *     pub fn sdn_to_mjd(sdn: Sdn) -> i64
+ *     pub fn mjd_to_sdn(mjd: i64) -> Result<Sdn, CalendarError>
+ *
+ * Convert a SDN to and from a Modified Julian Date, the Julian Date minus
+ * 2400000.5, which counts the days from midnight of November 17, 1858.
+ *
+ *     pub fn sdn_to_rata_die(sdn: Sdn) -> i64
+ *     pub fn rata_die_to_sdn(rata_die: i64) -> Result<Sdn, CalendarError>
+ *
+ * Convert a SDN to and from a Rata Die, which is 1 on January 1, 1 A.D.
+ * in the Gregorian calendar.
+ *
+ *     pub fn sdn_to_unix(sdn: Sdn, time: &StandardTime) -> i64
+ *     pub fn unix_to_sdn(seconds: i64) -> Result<(Sdn, StandardTime), CalendarError>
+ *
+ * Convert a day and a time of day to and from the seconds since January
+ * 1, 1970 at 00:00 UTC, without leap seconds as Unix time.  The fraction
+ * of second of the time is dropped.
+ *
+ *     pub fn sdn_to_excel_1900(sdn: Sdn) -> Result<i64, CalendarError>
+ *     pub fn excel_1900_to_sdn(serial: i64) -> Result<Sdn, CalendarError>
+ *     pub fn sdn_to_excel_1904(sdn: Sdn) -> Result<i64, CalendarError>
+ *     pub fn excel_1904_to_sdn(serial: i64) -> Result<Sdn, CalendarError>
+ *
+ * Convert a SDN to and from the serial date of a spreadsheet, in the 1900
+ * or the 1904 date system.  In the 1900 system, the serial 1 is January
+ * 1, 1900, and 1900 is taken for a leap year, as Lotus 1-2-3 did: the
+ * serial 60 is February 29, 1900, which does not exist, so that the
+ * serials from 61, March 1, 1900, are one more than the number of days
+ * since December 31, 1899.  excel_1900_to_sdn() returns InvalidDay for
+ * the serial 60.  In the 1904 system, the serial 0 is January 1, 1904.
+ * Both systems end with December 31, 9999, and OutOfRange is returned for
+ * a date or a serial outside of their range.
+ *
+ **************************************************************************/
+
+use libm::{floor, round};
+
+use crate::decimal_time::StandardTime;
+use crate::error::CalendarError;
+use crate::sdn::Sdn;
+
+const NANOSECONDS_PER_DAY: i64 = 86_400_000_000_000;
+const MILLISECONDS_PER_DAY: f64 = 86_400_000.0;
+const SECONDS_PER_DAY: i64 = 86_400;
+const MJD_OFFSET: i64 = 2400001; /* November 17, 1858 */
+const RATA_DIE_OFFSET: i64 = 1721425; /* December 31, 1 B.C. */
+const UNIX_EPOCH: i64 = 2440588; /* January 1, 1970 */
+const EXCEL_1900_EPOCH: i64 = 2415019; /* December 30, 1899 */
+const EXCEL_1900_LEAP_DAY: i64 = 60;
+const EXCEL_1900_LAST: i64 = 2958465; /* December 31, 9999 */
+const EXCEL_1904_EPOCH: i64 = 2416481; /* January 1, 1904 */
+const EXCEL_1904_LAST: i64 = 2957003; /* December 31, 9999 */
+
+fn sdn_at_offset(days: i64, offset: i64) -> Result<Sdn, CalendarError> {
+    Sdn::new(days.checked_add(offset).ok_or(CalendarError::OutOfRange)?)
+}
+
+pub fn sdn_to_julian_day(sdn: Sdn, time: &StandardTime) -> f64 {
+    sdn.value() as f64 - 0.5 + time.nanoseconds_of_day() as f64 / NANOSECONDS_PER_DAY as f64
+}
+
+pub fn julian_day_to_sdn(julian_day: f64) -> Result<(Sdn, StandardTime), CalendarError> {
+    if !julian_day.is_finite() {
+        return Err(CalendarError::OutOfRange);
+    }
+    /* Count the days from midnight.  Adding 0.5 and taking the fraction
+     * are exact. */
+    let civil_day = julian_day + 0.5;
+    let day = floor(civil_day);
+    let mut milliseconds = round((civil_day - day) * MILLISECONDS_PER_DAY) as i64;
+    let mut sdn = day as i64;
+    if milliseconds == MILLISECONDS_PER_DAY as i64 {
+        sdn += 1;
+        milliseconds = 0;
+    }
+    let time = StandardTime::from_nanoseconds_of_day(milliseconds * 1_000_000);
+    Ok((Sdn::new(sdn)?, time))
+}
+
+pub fn sdn_to_mjd(sdn: Sdn) -> i64 {
+    sdn.value() - MJD_OFFSET
+}
+
+pub fn mjd_to_sdn(mjd: i64) -> Result<Sdn, CalendarError> {
+    sdn_at_offset(mjd, MJD_OFFSET)
+}
+
+pub fn sdn_to_rata_die(sdn: Sdn) -> i64 {
+    sdn.value() - RATA_DIE_OFFSET
+}
+
+pub fn rata_die_to_sdn(rata_die: i64) -> Result<Sdn, CalendarError> {
+    sdn_at_offset(rata_die, RATA_DIE_OFFSET)
+}
+
+pub fn sdn_to_unix(sdn: Sdn, time: &StandardTime) -> i64 {
+    (sdn.value() - UNIX_EPOCH) * SECONDS_PER_DAY + time.nanoseconds_of_day() / 1_000_000_000
+}
+
+pub fn unix_to_sdn(seconds: i64) -> Result<(Sdn, StandardTime), CalendarError> {
+    let sdn = sdn_at_offset(seconds.div_euclid(SECONDS_PER_DAY), UNIX_EPOCH)?;
+    let time = StandardTime::from_nanoseconds_of_day(seconds.rem_euclid(SECONDS_PER_DAY) * 1_000_000_000);
+    Ok((sdn, time))
+}
+
+pub fn sdn_to_excel_1900(sdn: Sdn) -> Result<i64, CalendarError> {
+    /* The dates before March 1, 1900 come before the fake leap day. */
+    match sdn.value() - EXCEL_1900_EPOCH {
+        serial @ 2..=EXCEL_1900_LEAP_DAY => Ok(serial - 1),
+        serial @ 61..=EXCEL_1900_LAST => Ok(serial),
+        _ => Err(CalendarError::OutOfRange),
+    }
+}
+
+pub fn excel_1900_to_sdn(serial: i64) -> Result<Sdn, CalendarError> {
+    match serial {
+        1..=59 => Sdn::new(serial + EXCEL_1900_EPOCH + 1),
+        EXCEL_1900_LEAP_DAY => Err(CalendarError::InvalidDay),
+        61..=EXCEL_1900_LAST => Sdn::new(serial + EXCEL_1900_EPOCH),
+        _ => Err(CalendarError::OutOfRange),
+    }
+}
+
+pub fn sdn_to_excel_1904(sdn: Sdn) -> Result<i64, CalendarError> {
+    match sdn.value() - EXCEL_1904_EPOCH {
+        serial @ 0..=EXCEL_1904_LAST => Ok(serial),
+        _ => Err(CalendarError::OutOfRange),
+    }
+}
+
+pub fn excel_1904_to_sdn(serial: i64) -> Result<Sdn, CalendarError> {
+    match serial {
+        0..=EXCEL_1904_LAST => Sdn::new(serial + EXCEL_1904_EPOCH),
+        _ => Err(CalendarError::OutOfRange),
+    }
+}
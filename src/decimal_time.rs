@@ -0,0 +1,275 @@
+/**************************************************************************
+ *
+ * These are the externally visible components of this file:
+ *
+ *     pub fn standard_to_decimal(time: &StandardTime) -> DecimalTime
+ *
+ * Convert a standard time of day (24 hours of 60 minutes of 60 seconds) to
+ * a decimal time of day (10 hours of 100 minutes of 100 seconds).  The
+ * decimal time is truncated to the decimal nanosecond.
+ *
+ *     pub fn decimal_to_standard(time: &DecimalTime) -> StandardTime
+ *
+ * Convert a decimal time of day to a standard time of day.  The standard
+ * time is rounded up to the nanosecond, so that converting a standard time
+ * to decimal and back always gives the original time.  In particular, the
+ * round trip is exact for whole seconds.  The end of the decimal day,
+ * which would be rounded up to midnight, gives 23:59:59.999999999.
+ *
+ *     pub fn StandardTime::new(hour: i32, minute: i32, second: i32) -> Result<StandardTime, CalendarError>
+ *     pub fn StandardTime::with_nanosecond(hour: i32, minute: i32, second: i32, nanosecond: i32)
+ *         -> Result<StandardTime, CalendarError>
+ *     pub fn DecimalTime::new(hour: i32, minute: i32, second: i32) -> Result<DecimalTime, CalendarError>
+ *     pub fn DecimalTime::with_nanosecond(hour: i32, minute: i32, second: i32, nanosecond: i32)
+ *         -> Result<DecimalTime, CalendarError>
+ *
+ * Create a time of day, checking that each part is in range.  Otherwise
+ * CalendarError::InvalidTime is returned.  The parts are read with the
+ * hour(), minute(), second() and nanosecond() methods.  A decimal
+ * nanosecond is a billionth of a decimal second.
+ *
+ *     pub fn StandardTime::round_to_second(&self) -> StandardTime
+ *     pub fn DecimalTime::round_to_second(&self) -> DecimalTime
+ *
+ * Round a time of day to the nearest second, without going past the last
+ * second of the day.  As a decimal second is shorter than a second, a
+ * whole standard second converted to decimal, rounded to the decimal
+ * second, converted back and rounded to the second is the original
+ * second.
+ *
+ * A standard time is parsed from a string such as "13:43" or
+ * "13:43:40.8".  A standard time is displayed as "13:43:40" (followed by
+ * the fraction of second if not zero) and a decimal time is displayed as
+ * "5h 72m 00s décimales".
+ *
+ * OVERVIEW
+ *
+ *     Decimal time was decreed with the republican calendar, on 4
+ *     Frimaire an II (24 November 1793), and abandoned in 1795.  The day,
+ *     from midnight to midnight, is divided into 10 hours of 100 minutes
+ *     of 100 seconds.  A decimal second is thus 0.864 second and a
+ *     decimal hour 2 hours and 24 minutes.
+ *
+ **************************************************************************/
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::error::CalendarError;
+
+const NANOSECONDS_PER_SECOND: i64 = 1_000_000_000;
+const STANDARD_NANOSECONDS_PER_DAY: i64 = 86_400 * NANOSECONDS_PER_SECOND;
+const DECIMAL_NANOSECONDS_PER_DAY: i64 = 100_000 * NANOSECONDS_PER_SECOND;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StandardTime {
+    hour: i32,
+    minute: i32,
+    second: i32,
+    nanosecond: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DecimalTime {
+    hour: i32,
+    minute: i32,
+    second: i32,
+    nanosecond: i32,
+}
+
+impl StandardTime {
+    pub fn new(hour: i32, minute: i32, second: i32) -> Result<StandardTime, CalendarError> {
+        StandardTime::with_nanosecond(hour, minute, second, 0)
+    }
+
+    pub fn with_nanosecond(hour: i32, minute: i32, second: i32, nanosecond: i32)
+        -> Result<StandardTime, CalendarError> {
+        if !(0..24).contains(&hour) || !(0..60).contains(&minute) || !(0..60).contains(&second)
+            || !(0..NANOSECONDS_PER_SECOND as i32).contains(&nanosecond) {
+            Err(CalendarError::InvalidTime)
+        } else {
+            Ok(StandardTime {hour, minute, second, nanosecond})
+        }
+    }
+
+    pub fn hour(&self) -> i32 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> i32 {
+        self.minute
+    }
+
+    pub fn second(&self) -> i32 {
+        self.second
+    }
+
+    pub fn nanosecond(&self) -> i32 {
+        self.nanosecond
+    }
+
+    pub fn round_to_second(&self) -> StandardTime {
+        let seconds = round_to_second(self.nanoseconds_of_day(), STANDARD_NANOSECONDS_PER_DAY);
+        StandardTime {
+            hour: (seconds / 3600) as i32,
+            minute: (seconds / 60 % 60) as i32,
+            second: (seconds % 60) as i32,
+            nanosecond: 0,
+        }
+    }
+
+    pub(crate) fn nanoseconds_of_day(&self) -> i64 {
+        ((self.hour as i64 * 60 + self.minute as i64) * 60 + self.second as i64)
+            * NANOSECONDS_PER_SECOND + self.nanosecond as i64
+    }
+
+    /* The time at a number of nanoseconds since midnight, which must be
+     * less than a day. */
+    pub(crate) fn from_nanoseconds_of_day(nanoseconds: i64) -> StandardTime {
+        let seconds = nanoseconds / NANOSECONDS_PER_SECOND;
+        StandardTime {
+            hour: (seconds / 3600) as i32,
+            minute: (seconds / 60 % 60) as i32,
+            second: (seconds % 60) as i32,
+            nanosecond: (nanoseconds % NANOSECONDS_PER_SECOND) as i32,
+        }
+    }
+}
+
+impl DecimalTime {
+    pub fn new(hour: i32, minute: i32, second: i32) -> Result<DecimalTime, CalendarError> {
+        DecimalTime::with_nanosecond(hour, minute, second, 0)
+    }
+
+    pub fn with_nanosecond(hour: i32, minute: i32, second: i32, nanosecond: i32)
+        -> Result<DecimalTime, CalendarError> {
+        if !(0..10).contains(&hour) || !(0..100).contains(&minute) || !(0..100).contains(&second)
+            || !(0..NANOSECONDS_PER_SECOND as i32).contains(&nanosecond) {
+            Err(CalendarError::InvalidTime)
+        } else {
+            Ok(DecimalTime {hour, minute, second, nanosecond})
+        }
+    }
+
+    pub fn hour(&self) -> i32 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> i32 {
+        self.minute
+    }
+
+    pub fn second(&self) -> i32 {
+        self.second
+    }
+
+    pub fn nanosecond(&self) -> i32 {
+        self.nanosecond
+    }
+
+    pub fn round_to_second(&self) -> DecimalTime {
+        let seconds = round_to_second(self.nanoseconds_of_day(), DECIMAL_NANOSECONDS_PER_DAY);
+        DecimalTime {
+            hour: (seconds / 10_000) as i32,
+            minute: (seconds / 100 % 100) as i32,
+            second: (seconds % 100) as i32,
+            nanosecond: 0,
+        }
+    }
+
+    fn nanoseconds_of_day(&self) -> i64 {
+        ((self.hour as i64 * 100 + self.minute as i64) * 100 + self.second as i64)
+            * NANOSECONDS_PER_SECOND + self.nanosecond as i64
+    }
+}
+
+/* Round to the nearest second, the last second of the day at most. */
+fn round_to_second(nanoseconds: i64, nanoseconds_per_day: i64) -> i64 {
+    let seconds = (nanoseconds + NANOSECONDS_PER_SECOND / 2) / NANOSECONDS_PER_SECOND;
+    seconds.min(nanoseconds_per_day / NANOSECONDS_PER_SECOND - 1)
+}
+
+pub fn standard_to_decimal(time: &StandardTime) -> DecimalTime {
+    /* Use i128 as the product does not fit in 64 bits. */
+    let nanoseconds = (time.nanoseconds_of_day() as i128 * DECIMAL_NANOSECONDS_PER_DAY as i128
+        / STANDARD_NANOSECONDS_PER_DAY as i128) as i64;
+    let seconds = nanoseconds / NANOSECONDS_PER_SECOND;
+    DecimalTime {
+        hour: (seconds / 10_000) as i32,
+        minute: (seconds / 100 % 100) as i32,
+        second: (seconds % 100) as i32,
+        nanosecond: (nanoseconds % NANOSECONDS_PER_SECOND) as i32,
+    }
+}
+
+pub fn decimal_to_standard(time: &DecimalTime) -> StandardTime {
+    let product = time.nanoseconds_of_day() as i128 * STANDARD_NANOSECONDS_PER_DAY as i128;
+    let divisor = DECIMAL_NANOSECONDS_PER_DAY as i128;
+    let nanoseconds = ((product + divisor - 1) / divisor) as i64;
+    StandardTime::from_nanoseconds_of_day(nanoseconds.min(STANDARD_NANOSECONDS_PER_DAY - 1))
+}
+
+impl fmt::Display for StandardTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)?;
+        if self.nanosecond != 0 {
+            /* Write the fraction without its trailing zeros. */
+            let (mut fraction, mut digits) = (self.nanosecond, 9);
+            while fraction % 10 == 0 {
+                fraction /= 10;
+                digits -= 1;
+            }
+            write!(f, ".{:0width$}", fraction, width = digits)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for DecimalTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}h {:02}m {:02}s décimales", self.hour, self.minute, self.second)
+    }
+}
+
+impl FromStr for StandardTime {
+    type Err = CalendarError;
+
+    fn from_str(s: &str) -> Result<StandardTime, CalendarError> {
+        let parse = |part: &str| -> Result<i32, CalendarError> {
+            if part.is_empty() || part.len() > 2 || !part.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(CalendarError::InvalidTime);
+            }
+            part.parse().map_err(|_| CalendarError::InvalidTime)
+        };
+
+        let (time, fraction) = match s.trim().split_once('.') {
+            Some((time, fraction)) => (time, Some(fraction)),
+            None => (s.trim(), None),
+        };
+        let mut parts = time.split(':');
+        let hour = parse(parts.next().unwrap_or(""))?;
+        let minute = parse(parts.next().ok_or(CalendarError::InvalidTime)?)?;
+        let second = match parts.next() {
+            Some(second) => parse(second)?,
+            None if fraction.is_none() => 0,
+            None => return Err(CalendarError::InvalidTime),
+        };
+        if parts.next().is_some() {
+            return Err(CalendarError::InvalidTime);
+        }
+
+        let nanosecond = match fraction {
+            Some(fraction) => {
+                if fraction.is_empty() || fraction.len() > 9
+                    || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(CalendarError::InvalidTime);
+                }
+                fraction.bytes().chain(core::iter::repeat(b'0')).take(9)
+                    .fold(0, |n, b| n * 10 + (b - b'0') as i32)
+            }
+            None => 0,
+        };
+
+        StandardTime::with_nanosecond(hour, minute, second, nanosecond)
+    }
+}
@@ -0,0 +1,55 @@
+/**************************************************************************
+ *
+ * These are the externally visible components of this file:
+ *
+ *     pub enum CalendarError
+ *
+ * The reasons why a conversion routine can reject a date or a SDN:
+ *
+ *     OutOfRange            the date or the SDN is outside of the range
+ *                           supported by the calendar.
+ *     InvalidMonth          the month number does not exist in the
+ *                           calendar.
+ *     InvalidDay            the day number does not exist in this month.
+ *     NoSixthSansculottide  the date is the 6th complementary day of a
+ *                           French republican year that is not sextile.
+ *     YearZero              the year is zero, which does not exist in
+ *                           calendars counting years B.C. and A.D.
+ *     InvalidTime           the hour, minute, second or fraction of second
+ *                           of a time of day is out of range.
+ *
+ **************************************************************************/
+
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CalendarError {
+    OutOfRange,
+    InvalidMonth,
+    InvalidDay,
+    NoSixthSansculottide,
+    YearZero,
+    InvalidTime,
+}
+
+impl CalendarError {
+    /* The message of Display, also available at compile time. */
+    pub(crate) const fn message(self) -> &'static str {
+        match self {
+            CalendarError::OutOfRange => "date out of the supported range",
+            CalendarError::InvalidMonth => "invalid month",
+            CalendarError::InvalidDay => "invalid day for this month",
+            CalendarError::NoSixthSansculottide => "no 6th complementary day in a non-sextile year",
+            CalendarError::YearZero => "year zero does not exist",
+            CalendarError::InvalidTime => "invalid time of day",
+        }
+    }
+}
+
+impl fmt::Display for CalendarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for CalendarError {}
@@ -0,0 +1,297 @@
+/**************************************************************************
+ *
+ * This file is only compiled with the "alloc" feature.  These are the
+ * externally visible components of this file:
+ *
+ *     pub fn FrenchDate::format<'a>(&'a self, format: &'a str) -> Formatted<'a, FrenchDate>
+ *     pub fn GregorianDate::format<'a>(&'a self, format: &'a str) -> Formatted<'a, GregorianDate>
+ *
+ * Format a date according to a format string, in the manner of strftime().
+ * The returned value implements Display, so it can be printed or converted
+ * to a String without allocating an intermediate string.  For example:
+ *
+ *    let french_date = FrenchDate::new(2, 2, 20)?;
+ *    assert_eq!(french_date.format("%A %d %B an %Y").to_string(), "Décadi 20 Brumaire an II");
+ *
+ * The characters of the format string are copied to the output, except the
+ * directives which start with a '%' character.  The directives of the
+ * French republican dates are:
+ *
+ *     %A    name of the day of the décade (Primidi to Décadi), or name of
+ *           the complementary day (Jour de la Vertu to Jour de la
+ *           Révolution)
+ *     %a    abbreviated name of the day of the décade (Pri to Déc), or
+ *           short name of the complementary day (Vertu to Révolution)
+ *     %B    name of the month, Extra for the complementary days
+ *     %d    day of the month (01 to 30, 01 to 06 for complementary days)
+ *     %e    same as %_d
+ *     %m    number of the month (01 to 13)
+ *     %Y    year in Roman numerals (I, II...), or in Arabic numerals if it
+ *           is above 3999
+ *     %y    year in Arabic numerals
+ *     %j    day of the year (001 to 366)
+ *     %u    day of the décade (1 to 10), empty for complementary days
+ *     %D    décade of the year (01 to 36), empty for complementary days
+ *     %R    name of the day in the rural calendar (Raisin, Safran...)
+ *     %S    short name of the complementary day (Vertu to Révolution),
+ *           empty for the other days
+ *
+ * The directives of the Gregorian dates are:
+ *
+ *     %A    name of the day of the week (Sunday to Saturday)
+ *     %a    abbreviated name of the day of the week (Sun to Sat)
+ *     %B    name of the month (January to December)
+ *     %b    abbreviated name of the month (Jan to Dec)
+ *     %d    day of the month (01 to 31)
+ *     %e    same as %_d
+ *     %m    number of the month (01 to 12)
+ *     %Y    year, negative for the years B.C. (-1 is 1 B.C.)
+ *     %y    last two digits of the year (00 to 99)
+ *     %j    day of the year (001 to 366)
+ *     %u    day of the week (1 for Monday to 7 for Sunday)
+ *     %w    day of the week (0 for Sunday to 6 for Saturday)
+ *
+ * And for both:
+ *
+ *     %n    newline
+ *     %t    tab
+ *     %%    the character '%'
+ *
+ * A directive that is not recognized is copied to the output unchanged.
+ * Between the '%' and the directive character, there can be flags and a
+ * minimum field width, as with the GNU strftime():
+ *
+ *     -     do not pad a numeric field
+ *     _     pad a numeric field with spaces
+ *     0     pad a numeric field with zeros (the default)
+ *     ^     convert the field to upper case
+ *
+ * Text fields are padded with spaces on the left when a width is given.
+ *
+ *     pub fn Formatted::with_locale(self, locale: Locale) -> Formatted
+ *
+ * Give the names in another language (see locale::Locale).  By default,
+ * the names are the French ones for the French republican dates and the
+ * English ones for the Gregorian dates.  For example:
+ *
+ *    french_date.format("%A %d %B").with_locale(Locale::English)
+ *
+ *     impl Display for FrenchDate
+ *     impl Display for GregorianDate
+ *
+ * The dates are displayed with the formats "%A %-d %B an %Y" (e.g. Décadi
+ * 20 Brumaire an II), "%A an %Y" for the complementary days (e.g. Jour de
+ * la Vertu an II) and "%A %-d %B %Y" (e.g. Saturday 9 November 1799).
+ * They are padded or truncated as strings by the width, the fill, the
+ * alignment and the precision of the format, e.g. format!("{:>30}", date).
+ *
+ **************************************************************************/
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt::{self, Write};
+
+use crate::french_calendar::{FrenchDate, RepublicanDay};
+use crate::gregorian_calendar::{self, GregorianDate};
+use crate::locale::Locale;
+use crate::roman::to_roman;
+use crate::sdn::Sdn;
+
+pub struct Formatted<'a, T> {
+    date: &'a T,
+    format: &'a str,
+    locale: Option<Locale>,
+}
+
+impl<T> Formatted<'_, T> {
+    pub fn with_locale(self, locale: Locale) -> Self {
+        Formatted {locale: Some(locale), ..self}
+    }
+}
+
+/* The value of a directive and its default width. */
+enum Field {
+    Number(i64, usize),
+    Text(String),
+}
+
+fn name(name: &str) -> Field {
+    Field::Text(name.to_string())
+}
+
+/* Copy the format string to the output, replacing the directives with the
+ * field returned by the given function, or None if it is not known. */
+fn write_format<F>(f: &mut fmt::Formatter<'_>, format: &str, field: F) -> fmt::Result
+where
+    F: Fn(char) -> Option<Field>,
+{
+    let mut chars = format.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c != '%' {
+            f.write_char(c)?;
+            continue;
+        }
+
+        let mut pad = None;
+        let mut upper = false;
+        while let Some(&(_, flag)) = chars.peek() {
+            match flag {
+                '-' => pad = Some(None),
+                '_' => pad = Some(Some(' ')),
+                '0' => pad = Some(Some('0')),
+                '^' => upper = true,
+                _ => break,
+            }
+            chars.next();
+        }
+        let mut width = None;
+        while let Some(digit) = chars.peek().and_then(|&(_, c)| c.to_digit(10)) {
+            width = Some(width.unwrap_or(0) * 10 + digit as usize);
+            chars.next();
+        }
+
+        let Some((end, mut directive)) = chars.next() else {
+            f.write_str(&format[start..])?;
+            break;
+        };
+        if directive == 'e' {
+            directive = 'd';
+            pad = pad.or(Some(Some(' ')));
+        }
+        let value = match directive {
+            '%' => Field::Text("%".to_string()),
+            'n' => Field::Text("\n".to_string()),
+            't' => Field::Text("\t".to_string()),
+            _ => match field(directive) {
+                Some(value) => value,
+                None => {
+                    f.write_str(&format[start..end + directive.len_utf8()])?;
+                    continue;
+                }
+            },
+        };
+
+        let (text, width) = match value {
+            Field::Number(number, default_width) => {
+                let sign = if number < 0 {"-"} else {""};
+                let digits = number.unsigned_abs().to_string();
+                match pad.unwrap_or(Some('0')) {
+                    Some('0') => {
+                        let width = width.unwrap_or(default_width);
+                        let zeros = width.saturating_sub(sign.len() + digits.len());
+                        (format!("{}{}{}", sign, "0".repeat(zeros), digits), 0)
+                    }
+                    Some(_) => (format!("{}{}", sign, digits), width.unwrap_or(default_width)),
+                    None => (format!("{}{}", sign, digits), 0),
+                }
+            }
+            Field::Text(text) => (text, width.unwrap_or(0)),
+        };
+
+        let text = if upper {text.to_uppercase()} else {text};
+        for _ in text.chars().count()..width {
+            f.write_char(' ')?;
+        }
+        f.write_str(&text)?;
+    }
+    Ok(())
+}
+
+impl FrenchDate {
+    pub fn format<'a>(&'a self, format: &'a str) -> Formatted<'a, FrenchDate> {
+        Formatted {date: self, format, locale: None}
+    }
+}
+
+impl fmt::Display for Formatted<'_, FrenchDate> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let date = self.date;
+        let locale = self.locale.unwrap_or(Locale::French);
+        let sansculottide = date.sansculottide();
+        write_format(f, self.format, |directive| {
+            Some(match directive {
+                'A' => match sansculottide {
+                    Some(_) => name(locale.rural_day_name(date.day_of_year())),
+                    None => name(locale.decade_day_name(date.day_of_decade().unwrap_or(0))),
+                },
+                'a' => match sansculottide {
+                    Some(_) => name(locale.sansculottide_name(date.day())),
+                    None => name(locale.short_decade_day_name(date.day_of_decade().unwrap_or(0))),
+                },
+                'B' => name(locale.republican_month_name(date.month())),
+                'd' => Field::Number(date.day() as i64, 2),
+                'm' => Field::Number(date.month() as i64, 2),
+                'Y' => match to_roman(date.year()) {
+                    Some(roman) => Field::Text(roman),
+                    None => Field::Number(date.year() as i64, 0),
+                },
+                'y' => Field::Number(date.year() as i64, 0),
+                'j' => Field::Number(date.day_of_year() as i64, 3),
+                'u' => match date.day_of_decade() {
+                    Some(day) => Field::Number(day as i64, 0),
+                    None => Field::Text(String::new()),
+                },
+                'D' => match date.decade_of_year() {
+                    Some(decade) => Field::Number(decade as i64, 2),
+                    None => Field::Text(String::new()),
+                },
+                'R' => name(locale.rural_day_name(date.day_of_year())),
+                'S' => name(sansculottide.map_or("", |_| locale.sansculottide_name(date.day()))),
+                _ => return None,
+            })
+        })
+    }
+}
+
+impl fmt::Display for FrenchDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format = match self.republican_day() {
+            Some(RepublicanDay::Sansculottide(_)) => "%A an %Y",
+            _ => "%A %-d %B an %Y",
+        };
+        f.pad(&self.format(format).to_string())
+    }
+}
+
+impl GregorianDate {
+    pub fn format<'a>(&'a self, format: &'a str) -> Formatted<'a, GregorianDate> {
+        Formatted {date: self, format, locale: None}
+    }
+}
+
+impl fmt::Display for Formatted<'_, GregorianDate> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let date = self.date;
+        let locale = self.locale.unwrap_or(Locale::English);
+        let sdn = gregorian_calendar::try_gregorian_to_sdn(date).ok();
+        let day_of_week = sdn.map_or(-1, Sdn::day_of_week);
+        let day_of_year = sdn.map_or(0, |sdn| {
+            let first = GregorianDate::new(date.year(), 1, 1)
+                .and_then(|first| gregorian_calendar::try_gregorian_to_sdn(&first))
+                .unwrap_or(sdn);
+            (sdn - first).0 + 1
+        });
+        write_format(f, self.format, |directive| {
+            Some(match directive {
+                'A' => name(locale.day_of_week_name(day_of_week)),
+                'a' => name(locale.short_day_of_week_name(day_of_week)),
+                'B' => name(locale.gregorian_month_name(date.month())),
+                'b' => name(locale.short_gregorian_month_name(date.month())),
+                'd' => Field::Number(date.day() as i64, 2),
+                'm' => Field::Number(date.month() as i64, 2),
+                'Y' => Field::Number(date.year() as i64, 0),
+                'y' => Field::Number(date.year().rem_euclid(100) as i64, 2),
+                'j' => Field::Number(day_of_year as i64, 3),
+                'u' => Field::Number(if day_of_week == 0 {7} else {day_of_week} as i64, 0),
+                'w' => Field::Number(day_of_week as i64, 0),
+                _ => return None,
+            })
+        })
+    }
+}
+
+impl fmt::Display for GregorianDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&self.format("%A %-d %B %Y").to_string())
+    }
+}
@@ -11,24 +11,111 @@
  *
  * These are the externally visible components of this file:
  *
- *     pub fn sdn_to_french(sdn: i32) -> FrenchDate
+ *     pub const fn sdn_to_french(sdn: i32) -> FrenchDate
  *
  * Convert a SDN to a French republican calendar date.  If the input SDN is
  * before the first day of year 1 or after the last day of year 14, the
- * three output values will all be set to zero, otherwise *pYear will be in
+ * year and day of the output will be set to zero, otherwise *pYear will be in
  * the range 1 to 14 inclusive; *pMonth will be in the range 1 to 13
  * inclusive; *pDay will be in the range 1 to 30 inclusive.  If *pMonth is
  * 13, the SDN represents one of the holidays at the end of the year and
  * *pDay will be in the range 1 to 6 inclusive.
  *
- *     pub fn french_to_sdn(french_date: &FrenchDate) -> i32
+ *     pub const fn french_to_sdn(french_date: &FrenchDate) -> i32
  *
  * Convert a French republican calendar date to a SDN.  Zero is returned
- * when the input date is detected as invalid or out of the supported
- * range.  The return value will be > 0 for all valid, supported dates, but
- * there are some invalid dates that will return a positive value.  To
- * verify that a date is valid, convert it to SDN and then back and compare
- * with the original.
+ * when the input date is out of the supported range.  The return value
+ * will be > 0 for all supported dates.
+ *
+ *     pub fn sdn_to_french_with_rule(sdn: i32, rule: LeapRule) -> FrenchDate
+ *     pub fn french_to_sdn_with_rule(french_date: &FrenchDate, rule: LeapRule) -> i32
+ *
+ * Same as above, but for any year from 1 onwards, the sextile (leap) years
+ * being chosen by the given rule.  french_to_sdn_with_rule() also rejects
+ * a 6th complementary day in a year that is not sextile.
+ *
+ *     pub fn try_sdn_to_french(sdn: Sdn, rule: LeapRule) -> Result<FrenchDate, CalendarError>
+ *     pub fn try_french_to_sdn(french_date: &FrenchDate, rule: LeapRule) -> Result<Sdn, CalendarError>
+ *
+ * Same as the *_with_rule() routines, but the SDN is a Sdn (see sdn.rs)
+ * and the reason why a date or a SDN is rejected is returned instead of
+ * zero.  All the dates accepted by try_french_to_sdn() are valid.  The
+ * *_with_rule() routines are thin wrappers of them.
+ *
+ *     pub const fn FrenchDate::new(year: i32, month: i32, day: i32) -> Result<FrenchDate, CalendarError>
+ *     pub fn FrenchDate::new_with_rule(year: i32, month: i32, day: i32, rule: LeapRule)
+ *         -> Result<FrenchDate, CalendarError>
+ *
+ * Create a French republican date, checking that it is valid under the
+ * default leap year rule or under the given rule.  The fields of a
+ * FrenchDate are private, so a date is always valid, except the zero date
+ * returned by the routines above for an invalid SDN.  They are read with
+ * the year(), month() and day() methods.  FrenchDate::new() is a const
+ * fn, because the default rule is arithmetical, and so are the routines
+ * of years 1 to 14 above and the methods reading a date, so that dates
+ * can be checked at compile time (see literal.rs).
+ *
+ *     pub fn FrenchDate::from_republican_day(year: i32, day: RepublicanDay)
+ *         -> Result<FrenchDate, CalendarError>
+ *     pub fn FrenchDate::from_republican_day_with_rule(year: i32, day: RepublicanDay, rule: LeapRule)
+ *         -> Result<FrenchDate, CalendarError>
+ *
+ * Same as above, the month and day being given as a RepublicanDay.
+ *
+ *     pub fn FrenchDate::republican_day(&self) -> Option<RepublicanDay>
+ *     pub fn FrenchDate::republican_month(&self) -> Option<RepublicanMonth>
+ *     pub fn FrenchDate::sansculottide(&self) -> Option<Sansculottide>
+ *
+ * Return the day of the year of the date, either a day in a month or a
+ * complementary day.  The numeric month() and day() return 13 and 1 to 6
+ * for the complementary days.  The zero date returned for an invalid SDN
+ * has no day of the year: republican_day() returns None, and month() and
+ * day() return 0.
+ *
+ *     pub enum RepublicanMonth
+ *     pub enum Sansculottide
+ *     pub enum RepublicanDay
+ *     pub struct DayOfMonth
+ *
+ * The 12 months (Vendemiaire to Fructidor), the 6 complementary days, or
+ * sansculottides (Vertu, Genie, Travail, Opinion, Recompenses and
+ * Revolution), and a day of the year, which is either Month(month, day)
+ * or Sansculottide(sansculottide).  Months and sansculottides are
+ * converted to and from their number with from_number() and number(), and
+ * to their name with name().  Their ALL constant lists them in order.
+ * The day of a month is a DayOfMonth, which is always in the range 1 to
+ * 30: it is created with DayOfMonth::new(day), which returns InvalidDay
+ * otherwise, and read with get().  RepublicanDay::month(month, day) does
+ * both at once.
+ *
+ *     pub fn FrenchDate::is_valid(&self) -> bool
+ *     pub fn FrenchDate::is_valid_with_rule(&self, rule: LeapRule) -> bool
+ *
+ * Tell whether the date is valid under the default or the given rule.  A
+ * date created with one rule may not be valid under another one if it is
+ * the 6th complementary day of a year or if it is beyond year 1208.
+ *
+ *     pub enum LeapRule
+ *
+ * The rules that can be used to decide which years are sextile.  All of
+ * them agree on years 1 to 18, the sextile years being 3, 7, 11 and 15.
+ *
+ *     Continuous  every fourth year forever: 3, 7, 11, 15, 19, 23, etc.
+ *     Sextile     the historical sextiles 3, 7, 11 and 15, then every
+ *                 fourth year from year 20 on: 20, 24, 28, etc.
+ *     Romme       like Sextile, but years multiple of 100 are not sextile
+ *                 unless they are multiple of 400, and years multiple of
+ *                 4000 are not sextile (Romme's proposal).  This is the
+ *                 default rule.
+ *     VonMadler   like Sextile, but years multiple of 128 are not sextile
+ *                 (von Mädler's proposal).
+ *     Equinox     each year begins on the day of the true autumnal
+ *                 equinox at the Paris Observatory, as decreed in 1793.
+ *                 The equinox is computed by the astronomy module.
+ *
+ *     pub fn LeapRule::is_sextile(self, year: i32) -> bool
+ *
+ * Tell whether the given year has 6 complementary days under the rule.
  *
  *     pub const FRENCH_MONTH_NAME: &'static [&'static str]
  *
@@ -37,16 +124,76 @@
  * "extra" days at the end of the year) will return the string "Extra".  An
  * index of zero will return a zero length string.
  *
+ *     pub fn FrenchDate::day_of_year(&self) -> i32
+ *
+ * Return the number of the day in the year, in the range 1 to 366, the
+ * complementary days being the days 361 to 366.
+ *
+ *     pub fn FrenchDate::day_name(&self) -> &'static str
+ *
+ * Return the name given to the day of the year by Fabre d'Eglantine's
+ * rural calendar: a plant, an animal on each quintidi, a tool on each
+ * decadi, minerals in Nivose, and the festivals of the complementary
+ * days.  For example, 18 Brumaire is "Dentelaire".
+ *
+ *     pub fn find_day_by_name(name: &str) -> Option<RepublicanDay>
+ *
+ * Find the day of the year that has the given name in the rural calendar,
+ * ignoring the case, the accents and the difference between hyphens and
+ * spaces.  For example, find_day_by_name("carotte") returns
+ * Month(Vendemiaire, 7).  The complementary days are also found by the
+ * short name of their festival, such as "Vertu".
+ *
+ *     pub fn FrenchDate::decade(&self) -> Option<i32>
+ *     pub fn FrenchDate::decade_of_year(&self) -> Option<i32>
+ *
+ * Return the number of the decade (the republican week of 10 days) of the
+ * date, in the range 1 to 3 within the month or 1 to 36 within the year.
+ * The complementary days do not belong to any decade and return None.
+ *
+ *     pub fn FrenchDate::day_of_decade(&self) -> Option<i32>
+ *
+ * Return the day-of-decade number (1 to 10) of the date, where 1 stands
+ * for primidi, 2 for duodi, etc. and 10 for decadi.  The complementary
+ * days return None.
+ *
+ *     pub const SHORT_DECADE_DAY_NAME: &[&str]
+ *
+ * Convert a day-of-decade number (1 to 10), as returned from
+ * day_of_decade(), to the abbreviated (three character) name of the day.
+ * An index of zero will return a zero length string.
+ *
+ *     pub const DECADE_DAY_NAME: &[&str]
+ *
+ * Convert a day-of-decade number (1 to 10), as returned from
+ * day_of_decade(), to the name of the day.  An index of zero will return a
+ * zero length string.
+ *
+ *     pub const RURAL_DAY_NAME: &[&str]
+ *
+ * Convert a day-of-year number (1 to 366), as returned from
+ * day_of_year(), to its name in the rural calendar.  An index of zero will
+ * return a zero length string.
+ *
+ *     pub const SANSCULOTTIDE_NAME: &[&str]
+ *
+ * Convert a complementary day number (1 to 6) to the name of its
+ * festival.  An index of zero will return a zero length string.
+ *
  * VALID RANGE
  *
- *     These routines only convert dates in years 1 through 14 (Gregorian
- *     dates 22 September 1792 through 22 September 1806).  This more than
- *     covers the period when the calendar was in use.
+ *     sdn_to_french() and french_to_sdn() only convert dates in years 1
+ *     through 14 (Gregorian dates 22 September 1792 through 22 September
+ *     1806).  This more than covers the period when the calendar was in
+ *     use.
  *
- *     I would support a wider range of dates, but I have not been able to
- *     find an authoritative definition of when leap years were to have
- *     occurred.  There are suggestions that it was to skip a leap year ever
- *     100 years like the Gregorian calendar.
+ *     The calendar was abandoned before an authoritative definition of
+ *     when leap years were to occur was settled.  The *_with_rule()
+ *     routines extend the calendar to any year from 1 up to the end of
+ *     the range of Sdn (about year 998000), given one of the rules
+ *     proposed at the time.  Within years 1 to 14 all the rules give the same dates.
+ *     The Equinox rule is limited to years 1 to 1208 (Gregorian 3000),
+ *     after which the computation of the equinox is not accurate enough.
  *
  * CALENDAR OVERVIEW
  *
@@ -61,13 +208,16 @@
  *     each.
  *
  *     The epoch (first day of the first year) is 22 September 1792 in the
- *     Gregorian calendar.  Leap years are every fourth year (year 3, 7,
- *     11, etc.)
+ *     Gregorian calendar.  Leap years, called sextile years, are every
+ *     fourth year (year 3, 7, 11 and 15), the rule for later years being
+ *     one of those described by LeapRule above.
  *
  * TESTING
  *
- *     This algorithm has been tested from the year 1 to 14.  The source
- *     code of the verification program is included in this package.
+ *     This algorithm has been tested from the year 1 to 14, and from the
+ *     year 1 to 10000 for each arithmetical leap year rule (1 to 1208 for
+ *     the Equinox rule).  The source code of the verification program is
+ *     included in this package.
  *
  * REFERENCES
  *
@@ -75,15 +225,26 @@
  *     The algorithms are based on a preponderance of less authoritative
  *     sources.
  *
+ *     The names of the days are those of the Rapport fait à la Convention
+ *     nationale by Fabre d'Eglantine, 3 Brumaire an II (24 October 1793).
+ *
  **************************************************************************/
 
+use core::ops::RangeInclusive;
+
+use crate::astronomy;
+use crate::calendar::{self, CalendarDate};
+use crate::error::CalendarError;
+use crate::sdn::Sdn;
+
 const SDN_OFFSET: i32 = 2375474;
 const DAYS_PER_4_YEARS: i32 = 1461;
 const DAYS_PER_MONTH: i32 = 30;
 const FIRST_VALID: i32 = 2375840;
 const LAST_VALID: i32 = 2380952;
+const EQUINOX_LAST_YEAR: i32 = 1208;
 
-pub const FRENCH_MONTH_NAME: &'static [&'static str] = &[
+pub const FRENCH_MONTH_NAME: &[&str] = &[
     "",
     "Vendémiaire",
     "Brumaire",
@@ -100,53 +261,695 @@ pub const FRENCH_MONTH_NAME: &'static [&'static str] = &[
     "Extra"
 ];
 
-#[derive(Debug, PartialEq)]
+pub const SHORT_DECADE_DAY_NAME: &[&str] = &[
+    "",
+    "Pri",
+    "Duo",
+    "Tri",
+    "Qua",
+    "Qui",
+    "Sex",
+    "Sep",
+    "Oct",
+    "Non",
+    "Déc"
+];
+
+pub const DECADE_DAY_NAME: &[&str] = &[
+    "",
+    "Primidi",
+    "Duodi",
+    "Tridi",
+    "Quartidi",
+    "Quintidi",
+    "Sextidi",
+    "Septidi",
+    "Octidi",
+    "Nonidi",
+    "Décadi"
+];
+
+pub const SANSCULOTTIDE_NAME: &[&str] = &[
+    "",
+    "Vertu",
+    "Génie",
+    "Travail",
+    "Opinion",
+    "Récompenses",
+    "Révolution"
+];
+
+pub const RURAL_DAY_NAME: &[&str] = &[
+    "",
+    /* Vendémiaire */
+    "Raisin", "Safran", "Châtaigne", "Colchique", "Cheval",
+    "Balsamine", "Carotte", "Amaranthe", "Panais", "Cuve",
+    "Pomme de terre", "Immortelle", "Potiron", "Réséda", "Âne",
+    "Belle de nuit", "Citrouille", "Sarrasin", "Tournesol", "Pressoir",
+    "Chanvre", "Pêche", "Navet", "Amaryllis", "Bœuf",
+    "Aubergine", "Piment", "Tomate", "Orge", "Tonneau",
+    /* Brumaire */
+    "Pomme", "Céleri", "Poire", "Betterave", "Oie",
+    "Héliotrope", "Figue", "Scorsonère", "Alisier", "Charrue",
+    "Salsifis", "Mâcre", "Topinambour", "Endive", "Dindon",
+    "Chervis", "Cresson", "Dentelaire", "Grenade", "Herse",
+    "Bacchante", "Azerole", "Garance", "Orange", "Faisan",
+    "Pistache", "Macjonc", "Coing", "Cormier", "Rouleau",
+    /* Frimaire */
+    "Raiponce", "Turneps", "Chicorée", "Nèfle", "Cochon",
+    "Mâche", "Chou-fleur", "Miel", "Genièvre", "Pioche",
+    "Cire", "Raifort", "Cèdre", "Sapin", "Chevreuil",
+    "Ajonc", "Cyprès", "Lierre", "Sabine", "Hoyau",
+    "Érable à sucre", "Bruyère", "Roseau", "Oseille", "Grillon",
+    "Pignon", "Liège", "Truffe", "Olive", "Pelle",
+    /* Nivôse */
+    "Tourbe", "Houille", "Bitume", "Soufre", "Chien",
+    "Lave", "Terre végétale", "Fumier", "Salpêtre", "Fléau",
+    "Granit", "Argile", "Ardoise", "Grès", "Lapin",
+    "Silex", "Marne", "Pierre à chaux", "Marbre", "Van",
+    "Pierre à plâtre", "Sel", "Fer", "Cuivre", "Chat",
+    "Étain", "Plomb", "Zinc", "Mercure", "Crible",
+    /* Pluviôse */
+    "Lauréole", "Mousse", "Fragon", "Perce-neige", "Taureau",
+    "Laurier-thym", "Amadouvier", "Mézéréon", "Peuplier", "Coignée",
+    "Ellébore", "Brocoli", "Laurier", "Avelinier", "Vache",
+    "Buis", "Lichen", "If", "Pulmonaire", "Serpette",
+    "Thlaspi", "Thimelé", "Chiendent", "Traînasse", "Lièvre",
+    "Guède", "Noisetier", "Cyclamen", "Chélidoine", "Traîneau",
+    /* Ventôse */
+    "Tussilage", "Cornouiller", "Violier", "Troène", "Bouc",
+    "Asaret", "Alaterne", "Violette", "Marceau", "Bêche",
+    "Narcisse", "Orme", "Fumeterre", "Vélar", "Chèvre",
+    "Épinard", "Doronic", "Mouron", "Cerfeuil", "Cordeau",
+    "Mandragore", "Persil", "Cochléaria", "Pâquerette", "Thon",
+    "Pissenlit", "Sylvie", "Capillaire", "Frêne", "Plantoir",
+    /* Germinal */
+    "Primevère", "Platane", "Asperge", "Tulipe", "Poule",
+    "Bette", "Bouleau", "Jonquille", "Aulne", "Couvoir",
+    "Pervenche", "Charme", "Morille", "Hêtre", "Abeille",
+    "Laitue", "Mélèze", "Ciguë", "Radis", "Ruche",
+    "Gainier", "Romaine", "Marronnier", "Roquette", "Pigeon",
+    "Lilas", "Anémone", "Pensée", "Myrtille", "Greffoir",
+    /* Floréal */
+    "Rose", "Chêne", "Fougère", "Aubépine", "Rossignol",
+    "Ancolie", "Muguet", "Champignon", "Hyacinthe", "Râteau",
+    "Rhubarbe", "Sainfoin", "Bâton-d'or", "Chamérops", "Ver à soie",
+    "Consoude", "Pimprenelle", "Corbeille d'or", "Arroche", "Sarcloir",
+    "Statice", "Fritillaire", "Bourrache", "Valériane", "Carpe",
+    "Fusain", "Civette", "Buglosse", "Sénevé", "Houlette",
+    /* Prairial */
+    "Luzerne", "Hémérocalle", "Trèfle", "Angélique", "Canard",
+    "Mélisse", "Fromental", "Martagon", "Serpolet", "Faux",
+    "Fraise", "Bétoine", "Pois", "Acacia", "Caille",
+    "Œillet", "Sureau", "Pavot", "Tilleul", "Fourche",
+    "Barbeau", "Camomille", "Chèvrefeuille", "Caille-lait", "Tanche",
+    "Jasmin", "Verveine", "Thym", "Pivoine", "Chariot",
+    /* Messidor */
+    "Seigle", "Avoine", "Oignon", "Véronique", "Mulet",
+    "Romarin", "Concombre", "Échalote", "Absinthe", "Faucille",
+    "Coriandre", "Artichaut", "Girofle", "Lavande", "Chamois",
+    "Tabac", "Groseille", "Gesse", "Cerise", "Parc",
+    "Menthe", "Cumin", "Haricot", "Orcanète", "Pintade",
+    "Sauge", "Ail", "Vesce", "Blé", "Chalémie",
+    /* Thermidor */
+    "Épeautre", "Bouillon-blanc", "Melon", "Ivraie", "Bélier",
+    "Prêle", "Armoise", "Carthame", "Mûre", "Arrosoir",
+    "Panic", "Salicorne", "Abricot", "Basilic", "Brebis",
+    "Guimauve", "Lin", "Amande", "Gentiane", "Écluse",
+    "Carline", "Câprier", "Lentille", "Aunée", "Loutre",
+    "Myrte", "Colza", "Lupin", "Coton", "Moulin",
+    /* Fructidor */
+    "Prune", "Millet", "Lycoperdon", "Escourgeon", "Saumon",
+    "Tubéreuse", "Sucrion", "Apocyn", "Réglisse", "Échelle",
+    "Pastèque", "Fenouil", "Épine-vinette", "Noix", "Truite",
+    "Citron", "Cardère", "Nerprun", "Tagette", "Hotte",
+    "Églantier", "Noisette", "Houblon", "Sorgho", "Écrevisse",
+    "Bigarade", "Verge d'or", "Maïs", "Marron", "Panier",
+    /* Sansculottides */
+    "Jour de la Vertu", "Jour du Génie", "Jour du Travail", "Jour de l'Opinion", "Jour des Récompenses",
+    "Jour de la Révolution"
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeapRule {
+    Continuous,
+    Sextile,
+    Romme,
+    VonMadler,
+    Equinox,
+}
+
+/* The rules computed from the year number alone, which can be used at
+ * compile time: all of them but Equinox. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArithmeticRule {
+    Continuous,
+    Sextile,
+    Romme,
+    VonMadler,
+}
+
+const DEFAULT_RULE: ArithmeticRule = ArithmeticRule::Romme;
+
+impl Default for LeapRule {
+    fn default() -> LeapRule {
+        DEFAULT_RULE.leap_rule()
+    }
+}
+
+impl ArithmeticRule {
+    const fn leap_rule(self) -> LeapRule {
+        match self {
+            ArithmeticRule::Continuous => LeapRule::Continuous,
+            ArithmeticRule::Sextile => LeapRule::Sextile,
+            ArithmeticRule::Romme => LeapRule::Romme,
+            ArithmeticRule::VonMadler => LeapRule::VonMadler,
+        }
+    }
+
+    const fn is_sextile(self, year: i32) -> bool {
+        match self {
+            ArithmeticRule::Continuous => (year + 1) % 4 == 0,
+            _ if year < 19 => (year + 1) % 4 == 0,
+            ArithmeticRule::Sextile => year % 4 == 0,
+            ArithmeticRule::Romme => {
+                year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) && year % 4000 != 0
+            }
+            ArithmeticRule::VonMadler => year % 4 == 0 && year % 128 != 0,
+        }
+    }
+}
+
+impl LeapRule {
+    pub fn is_sextile(self, year: i32) -> bool {
+        match self.arithmetic() {
+            Some(rule) => rule.is_sextile(year),
+            None => self.year_start(year as i64 + 1) - self.year_start(year as i64) == 366,
+        }
+    }
+
+    const fn arithmetic(self) -> Option<ArithmeticRule> {
+        match self {
+            LeapRule::Continuous => Some(ArithmeticRule::Continuous),
+            LeapRule::Sextile => Some(ArithmeticRule::Sextile),
+            LeapRule::Romme => Some(ArithmeticRule::Romme),
+            LeapRule::VonMadler => Some(ArithmeticRule::VonMadler),
+            LeapRule::Equinox => None,
+        }
+    }
+
+    /* Last year that can be converted with this rule. */
+    const fn last_year(self) -> i32 {
+        match self {
+            LeapRule::Equinox => EQUINOX_LAST_YEAR,
+            _ => i32::MAX,
+        }
+    }
+
+    /* Number of sextile years from year 1 to the given year inclusive. */
+    fn sextiles_up_to(self, year: i64) -> i64 {
+        match self {
+            LeapRule::Equinox => self.year_start(year + 1) - FIRST_VALID as i64 - 365 * year,
+            LeapRule::Continuous => (year + 1) / 4,
+            _ if year < 19 => (year + 1) / 4,
+            LeapRule::Sextile => year / 4,
+            LeapRule::Romme => year / 4 - year / 100 + year / 400 - year / 4000,
+            LeapRule::VonMadler => year / 4 - year / 128,
+        }
+    }
+
+    /* SDN of the first day of the given year (year >= 1). */
+    fn year_start(self, year: i64) -> i64 {
+        match self {
+            LeapRule::Equinox => astronomy::paris_equinox_sdn((year + 1791) as i32) as i64,
+            _ => FIRST_VALID as i64 + 365 * (year - 1) + self.sextiles_up_to(year - 1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RepublicanMonth {
+    Vendemiaire = 1,
+    Brumaire,
+    Frimaire,
+    Nivose,
+    Pluviose,
+    Ventose,
+    Germinal,
+    Floreal,
+    Prairial,
+    Messidor,
+    Thermidor,
+    Fructidor,
+}
+
+impl RepublicanMonth {
+    pub const ALL: [RepublicanMonth; 12] = [
+        RepublicanMonth::Vendemiaire,
+        RepublicanMonth::Brumaire,
+        RepublicanMonth::Frimaire,
+        RepublicanMonth::Nivose,
+        RepublicanMonth::Pluviose,
+        RepublicanMonth::Ventose,
+        RepublicanMonth::Germinal,
+        RepublicanMonth::Floreal,
+        RepublicanMonth::Prairial,
+        RepublicanMonth::Messidor,
+        RepublicanMonth::Thermidor,
+        RepublicanMonth::Fructidor,
+    ];
+
+    pub const fn from_number(month: i32) -> Option<RepublicanMonth> {
+        if month >= 1 && month <= 12 {
+            Some(RepublicanMonth::ALL[month as usize - 1])
+        } else {
+            None
+        }
+    }
+
+    pub const fn number(self) -> i32 {
+        self as i32
+    }
+
+    pub const fn name(self) -> &'static str {
+        FRENCH_MONTH_NAME[self as usize]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Sansculottide {
+    Vertu = 1,
+    Genie,
+    Travail,
+    Opinion,
+    Recompenses,
+    Revolution,
+}
+
+impl Sansculottide {
+    pub const ALL: [Sansculottide; 6] = [
+        Sansculottide::Vertu,
+        Sansculottide::Genie,
+        Sansculottide::Travail,
+        Sansculottide::Opinion,
+        Sansculottide::Recompenses,
+        Sansculottide::Revolution,
+    ];
+
+    pub const fn from_number(day: i32) -> Option<Sansculottide> {
+        if day >= 1 && day <= 6 {
+            Some(Sansculottide::ALL[day as usize - 1])
+        } else {
+            None
+        }
+    }
+
+    pub const fn number(self) -> i32 {
+        self as i32
+    }
+
+    pub const fn name(self) -> &'static str {
+        SANSCULOTTIDE_NAME[self as usize]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DayOfMonth(i32);
+
+impl DayOfMonth {
+    pub const fn new(day: i32) -> Result<DayOfMonth, CalendarError> {
+        if day < 1 || day > DAYS_PER_MONTH {
+            Err(CalendarError::InvalidDay)
+        } else {
+            Ok(DayOfMonth(day))
+        }
+    }
+
+    pub const fn get(self) -> i32 {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RepublicanDay {
+    Month(RepublicanMonth, DayOfMonth),
+    Sansculottide(Sansculottide),
+}
+
+impl RepublicanDay {
+    pub const fn month(month: RepublicanMonth, day: i32) -> Result<RepublicanDay, CalendarError> {
+        match DayOfMonth::new(day) {
+            Ok(day) => Ok(RepublicanDay::Month(month, day)),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/* The day is None for the zero date returned for an invalid SDN. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct FrenchDate {
-    pub day: i32,
-    pub month: i32,
-    pub year: i32
+    year: i32,
+    day: Option<RepublicanDay>,
 }
 
 impl FrenchDate {
-    pub fn next_day(&mut self) {
-        self.day += 1;
-        if self.day > 30 {
-            self.day = 1;
-            self.month += 1;
+    pub const fn new(year: i32, month: i32, day: i32) -> Result<FrenchDate, CalendarError> {
+        match check_arithmetic_date(year, month, day, DEFAULT_RULE) {
+            Ok(()) => Ok(FrenchDate::from_numbers(year, month, day)),
+            Err(error) => Err(error),
         }
-        if self.month == 13 && self.day > 5 + if (self.year + 1) % 4 == 0 {1} else {0} {
-            self.day = 1;
-            self.month = 1;
-            self.year += 1;
+    }
+
+    pub fn new_with_rule(year: i32, month: i32, day: i32, rule: LeapRule)
+        -> Result<FrenchDate, CalendarError> {
+        check_date(year, month, day, rule)?;
+        Ok(FrenchDate::from_numbers(year, month, day))
+    }
+
+    pub fn from_republican_day(year: i32, day: RepublicanDay) -> Result<FrenchDate, CalendarError> {
+        FrenchDate::from_republican_day_with_rule(year, day, LeapRule::default())
+    }
+
+    pub fn from_republican_day_with_rule(year: i32, day: RepublicanDay, rule: LeapRule)
+        -> Result<FrenchDate, CalendarError> {
+        let french_date = FrenchDate {day: Some(day), year};
+        check_date(year, french_date.month(), french_date.day(), rule)?;
+        Ok(french_date)
+    }
+
+    /* The month and day must be in range, otherwise this is the zero date. */
+    const fn from_numbers(year: i32, month: i32, day: i32) -> FrenchDate {
+        let day = match (RepublicanMonth::from_number(month), Sansculottide::from_number(day)) {
+            (Some(month), _) => match RepublicanDay::month(month, day) {
+                Ok(day) => Some(day),
+                Err(_) => None,
+            },
+            (None, Some(sansculottide)) if month == 13 => Some(RepublicanDay::Sansculottide(sansculottide)),
+            _ => None,
+        };
+        FrenchDate {day, year}
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.is_valid_with_rule(LeapRule::default())
+    }
+
+    pub fn is_valid_with_rule(&self, rule: LeapRule) -> bool {
+        try_french_to_sdn(self, rule).is_ok()
+    }
+
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub const fn month(&self) -> i32 {
+        match self.day {
+            Some(RepublicanDay::Month(month, _)) => month.number(),
+            Some(RepublicanDay::Sansculottide(_)) => 13,
+            None => 0,
+        }
+    }
+
+    pub const fn day(&self) -> i32 {
+        match self.day {
+            Some(RepublicanDay::Month(_, day)) => day.get(),
+            Some(RepublicanDay::Sansculottide(sansculottide)) => sansculottide.number(),
+            None => 0,
+        }
+    }
+
+    pub const fn republican_day(&self) -> Option<RepublicanDay> {
+        self.day
+    }
+
+    pub const fn republican_month(&self) -> Option<RepublicanMonth> {
+        match self.day {
+            Some(RepublicanDay::Month(month, _)) => Some(month),
+            _ => None,
         }
     }
+
+    pub const fn sansculottide(&self) -> Option<Sansculottide> {
+        match self.day {
+            Some(RepublicanDay::Sansculottide(sansculottide)) => Some(sansculottide),
+            _ => None,
+        }
+    }
+
+    pub const fn day_of_year(&self) -> i32 {
+        match self.day {
+            Some(_) => (self.month() - 1) * DAYS_PER_MONTH + self.day(),
+            None => 0,
+        }
+    }
+
+    pub const fn day_name(&self) -> &'static str {
+        RURAL_DAY_NAME[self.day_of_year() as usize]
+    }
+
+    pub const fn decade(&self) -> Option<i32> {
+        match self.day {
+            Some(RepublicanDay::Month(_, day)) => Some((day.get() - 1) / 10 + 1),
+            _ => None,
+        }
+    }
+
+    pub const fn decade_of_year(&self) -> Option<i32> {
+        match self.day {
+            Some(RepublicanDay::Month(month, day)) => Some((month.number() - 1) * 3 + (day.get() - 1) / 10 + 1),
+            _ => None,
+        }
+    }
+
+    pub const fn day_of_decade(&self) -> Option<i32> {
+        match self.day {
+            Some(RepublicanDay::Month(_, day)) => Some((day.get() - 1) % 10 + 1),
+            _ => None,
+        }
+    }
+
+    pub fn next_day(&mut self) {
+        self.next_day_with_rule(LeapRule::default());
+    }
+
+    pub fn next_day_with_rule(&mut self, rule: LeapRule) {
+        const FIRST: DayOfMonth = DayOfMonth(1);
+
+        let Some(day) = self.day else {
+            return;
+        };
+        self.day = Some(match day {
+            RepublicanDay::Month(month, day) if day.get() < DAYS_PER_MONTH => {
+                RepublicanDay::Month(month, DayOfMonth(day.get() + 1))
+            }
+            RepublicanDay::Month(RepublicanMonth::Fructidor, _) => {
+                RepublicanDay::Sansculottide(Sansculottide::Vertu)
+            }
+            RepublicanDay::Month(month, _) => {
+                RepublicanDay::Month(RepublicanMonth::ALL[month as usize], FIRST)
+            }
+            RepublicanDay::Sansculottide(sansculottide) => {
+                let last = if rule.is_sextile(self.year) {6} else {5};
+                if sansculottide.number() < last {
+                    RepublicanDay::Sansculottide(Sansculottide::ALL[sansculottide as usize])
+                } else {
+                    self.year += 1;
+                    RepublicanDay::Month(RepublicanMonth::Vendemiaire, FIRST)
+                }
+            }
+        });
+    }
+}
+
+pub fn find_day_by_name(name: &str) -> Option<RepublicanDay> {
+    let name = name.trim();
+    let day_of_year = RURAL_DAY_NAME.iter().skip(1).position(|n| fold(n).eq(fold(name)));
+    if let Some(day_of_year) = day_of_year {
+        let (month, day) = (day_of_year as i32 / DAYS_PER_MONTH + 1, day_of_year as i32 % DAYS_PER_MONTH + 1);
+        return FrenchDate::from_numbers(1, month, day).day;
+    }
+
+    Sansculottide::ALL.iter()
+        .find(|sansculottide| fold(sansculottide.name()).eq(fold(name)))
+        .map(|&sansculottide| RepublicanDay::Sansculottide(sansculottide))
+}
+
+/* Fold a name to lower case and without accents, for loose comparisons. */
+pub(crate) fn fold(name: &str) -> impl Iterator<Item = char> + '_ {
+    name.chars().flat_map(|c| {
+        let (first, second) = match c {
+            'à' | 'â' | 'ä' | 'À' | 'Â' | 'Ä' => ('a', None),
+            'é' | 'è' | 'ê' | 'ë' | 'É' | 'È' | 'Ê' | 'Ë' => ('e', None),
+            'î' | 'ï' | 'Î' | 'Ï' => ('i', None),
+            'ô' | 'ö' | 'Ô' | 'Ö' => ('o', None),
+            'ù' | 'û' | 'ü' | 'Ù' | 'Û' | 'Ü' => ('u', None),
+            'ÿ' | 'Ÿ' => ('y', None),
+            'ç' | 'Ç' => ('c', None),
+            'œ' | 'Œ' => ('o', Some('e')),
+            'æ' | 'Æ' => ('a', Some('e')),
+            '’' => ('\'', None),
+            '-' => (' ', None),
+            c => (c.to_ascii_lowercase(), None),
+        };
+        core::iter::once(first).chain(second)
+    })
+}
+
+/* Check the date, except the 6th complementary day. */
+const fn check_fields(year: i32, month: i32, day: i32, last_year: i32) -> Result<(), CalendarError> {
+    if year == 0 {
+        return Err(CalendarError::YearZero);
+    }
+    if year < 1 || year > last_year {
+        return Err(CalendarError::OutOfRange);
+    }
+    if month < 1 || month > 13 {
+        return Err(CalendarError::InvalidMonth);
+    }
+    if day < 1 || day > if month == 13 {6} else {30} {
+        return Err(CalendarError::InvalidDay);
+    }
+    Ok(())
+}
+
+/* Same as check_date() for the arithmetical rules, which is const. */
+const fn check_arithmetic_date(year: i32, month: i32, day: i32, rule: ArithmeticRule)
+    -> Result<(), CalendarError> {
+    if let Err(error) = check_fields(year, month, day, rule.leap_rule().last_year()) {
+        return Err(error);
+    }
+    if month == 13 && day == 6 && !rule.is_sextile(year) {
+        return Err(CalendarError::NoSixthSansculottide);
+    }
+    Ok(())
+}
+
+fn check_date(year: i32, month: i32, day: i32, rule: LeapRule) -> Result<(), CalendarError> {
+    if let Some(rule) = rule.arithmetic() {
+        return check_arithmetic_date(year, month, day, rule);
+    }
+    check_fields(year, month, day, rule.last_year())?;
+    if month == 13 && day == 6 && !rule.is_sextile(year) {
+        return Err(CalendarError::NoSixthSansculottide);
+    }
+    Ok(())
 }
 
-pub fn sdn_to_french(sdn: i32) -> FrenchDate {
+pub const fn sdn_to_french(sdn: i32) -> FrenchDate {
     if sdn < FIRST_VALID || sdn > LAST_VALID {
-        FrenchDate {
-           day: 0,
-           month: 0,
-           year: 0,
-        }
+        FrenchDate::from_numbers(0, 0, 0)
     } else {
         let temp = (sdn - SDN_OFFSET) * 4 - 1;
         let day_of_year: i32 = (temp % DAYS_PER_4_YEARS) / 4;
-        FrenchDate {
-           day: day_of_year % DAYS_PER_MONTH + 1,
-           month:  day_of_year / DAYS_PER_MONTH + 1,
-           year: temp / DAYS_PER_4_YEARS,
-        }
+        FrenchDate::from_numbers(
+            temp / DAYS_PER_4_YEARS,
+            day_of_year / DAYS_PER_MONTH + 1,
+            day_of_year % DAYS_PER_MONTH + 1,
+        )
     }
 }
 
-pub fn french_to_sdn(french_date: &FrenchDate) -> i32 {
+pub const fn french_to_sdn(french_date: &FrenchDate) -> i32 {
     /* check for invalid dates */
-    if french_date.year < 1 || french_date.year > 14 || french_date.month < 1
-        || french_date.month > 13 || french_date.day < 1 || french_date.day > 30 {
+    if french_date.year < 1 || french_date.year > 14 || french_date.day() < 1 {
         0
     } else {
-        (french_date.year * DAYS_PER_4_YEARS) / 4 + (french_date.month - 1) * DAYS_PER_MONTH
-        + french_date.day + SDN_OFFSET
+        (french_date.year * DAYS_PER_4_YEARS) / 4 + (french_date.month() - 1) * DAYS_PER_MONTH
+        + french_date.day() + SDN_OFFSET
+    }
+}
+
+pub fn sdn_to_french_with_rule(sdn: i32, rule: LeapRule) -> FrenchDate {
+    Sdn::new(sdn as i64)
+        .and_then(|sdn| try_sdn_to_french(sdn, rule))
+        .unwrap_or(FrenchDate::from_numbers(0, 0, 0))
+}
+
+pub fn french_to_sdn_with_rule(french_date: &FrenchDate, rule: LeapRule) -> i32 {
+    try_french_to_sdn(french_date, rule).map_or(0, |sdn| sdn.value() as i32)
+}
+
+pub fn try_sdn_to_french(sdn: Sdn, rule: LeapRule) -> Result<FrenchDate, CalendarError> {
+    let sdn = sdn.value();
+    if sdn < FIRST_VALID as i64 || sdn >= rule.year_start(rule.last_year() as i64 + 1) {
+        return Err(CalendarError::OutOfRange);
+    }
+
+    /* Estimate the year, then correct the estimation. */
+    let mut year: i64 = (sdn - FIRST_VALID as i64) * 4 / DAYS_PER_4_YEARS as i64 + 1;
+    while rule.year_start(year) > sdn {
+        year -= 1;
     }
+    while rule.year_start(year + 1) <= sdn {
+        year += 1;
+    }
+
+    let day_of_year = (sdn - rule.year_start(year)) as i32;
+    Ok(FrenchDate::from_numbers(
+        year as i32,
+        day_of_year / DAYS_PER_MONTH + 1,
+        day_of_year % DAYS_PER_MONTH + 1,
+    ))
 }
+
+pub fn try_french_to_sdn(french_date: &FrenchDate, rule: LeapRule) -> Result<Sdn, CalendarError> {
+    /* check for invalid dates */
+    check_date(french_date.year, french_date.month(), french_date.day(), rule)?;
+
+    let sdn = rule.year_start(french_date.year as i64)
+        + ((french_date.month() - 1) * DAYS_PER_MONTH + french_date.day() - 1) as i64;
+    Sdn::new(sdn)
+}
+
+impl CalendarDate for FrenchDate {
+    fn from_ymd(year: i32, month: i32, day: i32) -> Result<FrenchDate, CalendarError> {
+        FrenchDate::new(year, month, day)
+    }
+
+    fn year(&self) -> i32 {
+        self.year
+    }
+
+    fn month(&self) -> i32 {
+        FrenchDate::month(self)
+    }
+
+    fn day(&self) -> i32 {
+        FrenchDate::day(self)
+    }
+
+    fn to_sdn(&self) -> Result<Sdn, CalendarError> {
+        try_french_to_sdn(self, LeapRule::default())
+    }
+
+    fn from_sdn(sdn: Sdn) -> Result<FrenchDate, CalendarError> {
+        try_sdn_to_french(sdn, LeapRule::default())
+    }
+
+    fn valid_range() -> RangeInclusive<Sdn> {
+        Sdn::from_valid(FIRST_VALID as i64)..=Sdn::MAX
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        LeapRule::default().is_sextile(year)
+    }
+
+    fn days_in_month(year: i32, month: i32) -> i32 {
+        match month {
+            1..=12 => DAYS_PER_MONTH,
+            13 if FrenchDate::is_leap_year(year) => 6,
+            13 => 5,
+            _ => 0,
+        }
+    }
+
+    fn month_name(_year: i32, month: i32) -> &'static str {
+        FRENCH_MONTH_NAME.get(month.max(0) as usize).copied().unwrap_or("")
+    }
+
+    fn next_day(&mut self) {
+        FrenchDate::next_day(self)
+    }
+}
+
+calendar::impl_date_arithmetic!(FrenchDate);
@@ -11,29 +11,48 @@
  *
  * These are the externally visible components of this file:
  *
- *     pub fn sdn_to_gregorian(sdn: i32) -> GregorianDate
+ *     pub const fn sdn_to_gregorian(sdn: i32) -> GregorianDate
  *
  * Convert a SDN to a Gregorian calendar date.  If the input SDN is less
  * than 1, the three output values will all be set to zero, otherwise
  * *pYear will be >= -4714 and != 0; *pMonth will be in the range 1 to 12
- * inclusive; *pDay will be in the range 1 to 31 inclusive.
+ * inclusive; *pDay will be in the range 1 to 31 inclusive.  Use
+ * try_sdn_to_gregorian() for the SDNs less than 1.
  *
- *     pub fn gregorian_to_sdn(gregorian_date: &GregorianDate) -> i32
+ *     pub const fn gregorian_to_sdn(gregorian_date: &GregorianDate) -> i32
  *
  * Convert a Gregorian calendar date to a SDN.  Zero is returned when the
  * input date is detected as invalid or out of the supported range.  The
- * return value will be > 0 for all valid, supported dates, but there are
- * some invalid dates that will return a positive value.  To verify that a
- * date is valid, convert it to SDN and then back and compare with the
- * original.
+ * return value will be > 0 for all valid, supported dates, so the dates
+ * before November 25, 4714 B.C. are rejected.
  *
- *     pub const SHORT_MONTH_NAME: &'static [&'static str]
+ *     pub const fn try_sdn_to_gregorian(sdn: Sdn) -> Result<GregorianDate, CalendarError>
+ *     pub const fn try_gregorian_to_sdn(gregorian_date: &GregorianDate) -> Result<Sdn, CalendarError>
+ *
+ * Same as above, but the SDN is a Sdn (see sdn.rs) and the reason why a
+ * date or a SDN is rejected is returned instead of zero.  These routines
+ * also handle the SDNs less than 1, down to January 1, 1000000 B.C.  The
+ * routines above are thin wrappers of them.
+ *
+ *     pub const fn GregorianDate::new(year: i32, month: i32, day: i32) -> Result<GregorianDate, CalendarError>
+ *
+ * Create a Gregorian date, checking that it is valid and within the
+ * supported range.  The fields of a GregorianDate are private, so a date
+ * is always valid, except the zero date returned by sdn_to_gregorian() for
+ * an invalid SDN.  They are read with the year(), month() and day()
+ * methods.
+ *
+ *     pub const fn GregorianDate::is_valid(&self) -> bool
+ *
+ * Tell whether the date is valid.
+ *
+ *     pub const SHORT_MONTH_NAME: &[&str]
  *
  * Convert a Gregorian month number (1 to 12) to the abbreviated (three
  * character) name of the Gregorian month (null terminated).  An index of
  * zero will return a zero length string.
  *
- *     pub const LONG_MONTH_NAME: &'static [&'static str]
+ *     pub const LONG_MONTH_NAME: &[&str]
  *
  * Convert a Gregorian month number (1 to 12) to the name of the Gregorian
  * month (null terminated).  An index of zero will return a zero length
@@ -41,9 +60,10 @@
  *
  * VALID RANGE
  *
- *     4714 B.C. to at least 10000 A.D.
+ *     1000000 B.C. to 1000000 A.D., 4714 B.C. to 1000000 A.D. for the
+ *     routines returning zero for an invalid date.
  *
- *     Although this software can handle dates all the way back to 4714
+ *     Although this software can handle dates all the way back to 1000000
  *     B.C., such use may not be meaningful.  The Gregorian calendar was
  *     not instituted until October 15, 1582 (or October 5, 1582 in the
  *     Julian calendar).  Some countries did not accept it until much
@@ -51,6 +71,10 @@
  *     Greece in 1923.  Most European countries used the Julian calendar
  *     prior to the Gregorian.
  *
+ *     Dates before the year 1000000 B.C. or after the year 1000000 A.D.
+ *     are rejected as out of range.  The calculations are done on 64 bits,
+ *     so that they do not overflow.
+ *
  * CALENDAR OVERVIEW
  *
  *     The Gregorian calendar is a modified version of the Julian calendar.
@@ -104,11 +128,15 @@
  *     For this reason the calculations (internally) assume that the
  *     year starts with March 1.
  *
+ *     The calculations only work on positive numbers, so the years are
+ *     shifted by 2500 cycles of 400 years (1000000 years) before them.
+ *
  * TESTING
  *
  *     This algorithm has been tested from the year 4714 B.C. to 10000
  *     A.D.  The source code of the verification program is included in
- *     this package.
+ *     this package.  The dates around 1000000 B.C., 1 B.C. and 1000000
+ *     A.D. are also tested.
  *
  * REFERENCES
  *
@@ -119,46 +147,57 @@
  *
  **************************************************************************/
 
-const SDN_OFFSET: i32 = 32045;
-const DAYS_PER_5_MONTHS: i32 = 153;
-const DAYS_PER_4_YEARS: i32 = 1461;
-const DAYS_PER_400_YEARS: i32 = 146097;
+use core::ops::RangeInclusive;
+
+use crate::calendar::{self, CalendarDate};
+use crate::error::CalendarError;
+use crate::sdn::Sdn;
 
-#[derive(Debug, PartialEq)]
+const SDN_OFFSET: i64 = 32045;
+const DAYS_PER_5_MONTHS: i64 = 153;
+const DAYS_PER_4_YEARS: i64 = 1461;
+const DAYS_PER_400_YEARS: i64 = 146097;
+const SHIFT_CYCLES: i64 = 2500;
+const FIRST_YEAR: i32 = -1000000;
+const LAST_YEAR: i32 = 1000000;
+const FIRST_VALID: i64 = -363521074; /* January 1, 1000000 B.C. */
+const LAST_VALID: i64 = 366963925; /* December 31, 1000000 */
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct GregorianDate {
-    pub day: i32,
-    pub month: i32,
-    pub year: i32,
+    year: i32,
+    month: i32,
+    day: i32,
 }
 
 impl GregorianDate {
-    fn feb_length(&self) -> i32 {
-        let mut y = self.year;
-        if y < 0 {
-            y += 1;
-        }
-        y += 4800;
-
-        if y % 4 == 0 {
-            if y % 100 == 0 {
-                if y % 400 == 0 {
-                    29
-                } else {
-                    28
-                }
-            } else {
-                29
-            }
-        } else {
-            28
+    pub const fn new(year: i32, month: i32, day: i32) -> Result<GregorianDate, CalendarError> {
+        let gregorian_date = GregorianDate {day, month, year};
+        match try_gregorian_to_sdn(&gregorian_date) {
+            Ok(_) => Ok(gregorian_date),
+            Err(error) => Err(error),
         }
     }
 
+    pub const fn is_valid(&self) -> bool {
+        try_gregorian_to_sdn(self).is_ok()
+    }
+
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub const fn month(&self) -> i32 {
+        self.month
+    }
+
+    pub const fn day(&self) -> i32 {
+        self.day
+    }
+
     pub fn next_day(&mut self) {
-        const MONTH_LENGTH: [i32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
         self.day += 1;
-        let month_length = if self.month == 2 {self.feb_length()} else {MONTH_LENGTH[self.month as usize-1]};
-        if self.day > month_length {
+        if self.day > month_length(self.year, self.month) {
             self.day = 1;
             self.month += 1;
             if self.month > 12 {
@@ -172,7 +211,34 @@ impl GregorianDate {
     }
 }
 
-pub const SHORT_MONTH_NAME: &'static [&'static str] = &[
+const fn month_length(year: i32, month: i32) -> i32 {
+    const MONTH_LENGTH: [i32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    if month != 2 {
+        return MONTH_LENGTH[month as usize - 1];
+    }
+
+    let mut y = year;
+    if y < 0 {
+        y += 1;
+    }
+    y += 4800;
+
+    if y % 4 == 0 {
+        if y % 100 == 0 {
+            if y % 400 == 0 {
+                29
+            } else {
+                28
+            }
+        } else {
+            29
+        }
+    } else {
+        28
+    }
+}
+
+pub const SHORT_MONTH_NAME: &[&str] = &[
     "",
     "Jan",
     "Feb",
@@ -188,7 +254,7 @@ pub const SHORT_MONTH_NAME: &'static [&'static str] = &[
     "Dec"
 ];
 
-pub const LONG_MONTH_NAME: &'static [&'static str] = &[
+pub const LONG_MONTH_NAME: &[&str] = &[
     "",
     "January",
     "February",
@@ -204,28 +270,47 @@ pub const LONG_MONTH_NAME: &'static [&'static str] = &[
     "December"
 ];
 
-pub fn sdn_to_gregorian(sdn: i32) -> GregorianDate {
-    if sdn <= 0 {
-        GregorianDate {
-           day: 0,
-           month: 0,
-           year: 0,
-        }
+pub const fn sdn_to_gregorian(sdn: i32) -> GregorianDate {
+    let result = match Sdn::new(sdn as i64) {
+        Ok(sdn) => try_sdn_to_gregorian(sdn),
+        Err(error) => Err(error),
+    };
+    match result {
+        Ok(gregorian_date) if sdn > 0 => gregorian_date,
+        _ => GregorianDate {
+            day: 0,
+            month: 0,
+            year: 0,
+        },
+    }
+}
+
+pub const fn gregorian_to_sdn(gregorian_date: &GregorianDate) -> i32 {
+    match try_gregorian_to_sdn(gregorian_date) {
+        Ok(sdn) if sdn.value() > 0 => sdn.value() as i32,
+        _ => 0,
+    }
+}
+
+pub const fn try_sdn_to_gregorian(sdn: Sdn) -> Result<GregorianDate, CalendarError> {
+    let sdn = sdn.value();
+    if sdn < FIRST_VALID || sdn > LAST_VALID {
+        Err(CalendarError::OutOfRange)
     } else {
-        let mut temp: i32 = (sdn + SDN_OFFSET) * 4 - 1;
+        let mut temp: i64 = (sdn + SDN_OFFSET + SHIFT_CYCLES * DAYS_PER_400_YEARS) * 4 - 1;
 
         /* Calculate the century (year/100). */
-        let century: i32 = temp / DAYS_PER_400_YEARS;
+        let century: i64 = temp / DAYS_PER_400_YEARS;
 
         /* Calculate the year and day of year (1 <= day_of_year <= 366). */
         temp = ((temp % DAYS_PER_400_YEARS) / 4) * 4 + 3;
-        let mut year: i32 = (century * 100) + (temp / DAYS_PER_4_YEARS);
-        let day_of_year: i32 = (temp % DAYS_PER_4_YEARS) / 4 + 1;
+        let mut year: i64 = (century * 100) + (temp / DAYS_PER_4_YEARS);
+        let day_of_year: i64 = (temp % DAYS_PER_4_YEARS) / 4 + 1;
 
         /* Calculate the month and day of month. */
         temp = day_of_year * 5 - 3;
-        let mut month: i32 = temp / DAYS_PER_5_MONTHS;
-        let day: i32 = (temp % DAYS_PER_5_MONTHS) / 5 + 1;
+        let mut month: i64 = temp / DAYS_PER_5_MONTHS;
+        let day: i64 = (temp % DAYS_PER_5_MONTHS) / 5 + 1;
 
         /* Convert to the normal beginning of the year. */
         if month < 10 {
@@ -236,53 +321,49 @@ pub fn sdn_to_gregorian(sdn: i32) -> GregorianDate {
         }
 
         /* Adjust to the B.C./A.D. type numbering. */
-        year -= 4800;
+        year -= 4800 + SHIFT_CYCLES * 400;
         if year <= 0 {
             year -= 1;
         }
 
-        GregorianDate {year, month, day}
+        Ok(GregorianDate {year: year as i32, month: month as i32, day: day as i32})
     }
 }
 
-
-pub fn gregorian_to_sdn(gregorian_date: &GregorianDate) -> i32 {
+pub const fn try_gregorian_to_sdn(gregorian_date: &GregorianDate) -> Result<Sdn, CalendarError> {
     /* check for invalid dates */
-    if gregorian_date.year == 0 || gregorian_date.year < -4714
-        || gregorian_date.month <= 0 || gregorian_date.month > 12
-        || gregorian_date.day <= 0 || gregorian_date.day > 31 {
-        0
+    if gregorian_date.year == 0 {
+        Err(CalendarError::YearZero)
+    } else if gregorian_date.year < FIRST_YEAR || gregorian_date.year > LAST_YEAR {
+        Err(CalendarError::OutOfRange)
+    } else if gregorian_date.month <= 0 || gregorian_date.month > 12 {
+        Err(CalendarError::InvalidMonth)
+    } else if gregorian_date.day <= 0
+        || gregorian_date.day > month_length(gregorian_date.year, gregorian_date.month) {
+        Err(CalendarError::InvalidDay)
     } else {
-        /* check for dates before SDN 1 (Nov 25, 4714 B.C.) */
-        if gregorian_date.year == -4714
-            && (gregorian_date.month < 11
-                || (gregorian_date.month == 11 && gregorian_date.day < 25)
-            ) {
-            0
-        } else {
-            /* Make year always a positive number. */
-            let mut year;
-            if gregorian_date.year < 0 {
-                year = gregorian_date.year + 4801;
-            } else {
-                year = gregorian_date.year + 4800;
-            }
-
-            /* Adjust the start of the year. */
-            let month;
-            if gregorian_date.month > 2 {
-                month = gregorian_date.month - 3;
-            } else {
-                month = gregorian_date.month + 9;
-                year -= 1;
-            }
+        /* Make year always a positive number. */
+        let mut year: i64 = gregorian_date.year as i64 + 4800 + SHIFT_CYCLES * 400;
+        if gregorian_date.year < 0 {
+            year += 1;
+        }
 
-            ((year / 100) * DAYS_PER_400_YEARS) / 4
-                + ((year % 100) * DAYS_PER_4_YEARS) / 4
-                + (month * DAYS_PER_5_MONTHS + 2) / 5
-                + gregorian_date.day
-                - SDN_OFFSET
+        /* Adjust the start of the year. */
+        let month: i64;
+        if gregorian_date.month > 2 {
+            month = gregorian_date.month as i64 - 3;
+        } else {
+            month = gregorian_date.month as i64 + 9;
+            year -= 1;
         }
+
+        let sdn = ((year / 100) * DAYS_PER_400_YEARS) / 4
+            + ((year % 100) * DAYS_PER_4_YEARS) / 4
+            + (month * DAYS_PER_5_MONTHS + 2) / 5
+            + gregorian_date.day as i64
+            - SDN_OFFSET
+            - SHIFT_CYCLES * DAYS_PER_400_YEARS;
+        Sdn::new(sdn)
     }
 }
 
@@ -290,24 +371,24 @@ pub fn gregorian_to_sdn(gregorian_date: &GregorianDate) -> i32 {
  *
  * These are the externally visible components of this file:
  *
- *     pub fn day_of_week(sdn: i32) -> i32 {
+ *     pub const fn day_of_week(sdn: i32) -> i32 {
  *
  * Convert a SDN to a day-of-week number (0 to 6).  Where 0 stands for
  * Sunday, 1 for Monday, etc. and 6 stands for Saturday.
  *
- *     pub const SHORT_DAY_NAME: &'static [&'static str] = &[
+ *     pub const SHORT_DAY_NAME: &[&str] = &[
  *
  * Convert a day-of-week number (0 to 6), as returned from DayOfWeek(), to
  * the abbreviated (three character) name of the day.
  *
- *     pub const LONG_DAY_NAME: &'static [&'static str] = &[
+ *     pub const LONG_DAY_NAME: &[&str] = &[
  *
  * Convert a day-of-week number (0 to 6), as returned from DayOfWeek(), to
  * the name of the day.
  *
  **************************************************************************/
 
-pub const SHORT_DAY_NAME: &'static [&'static str] = &[
+pub const SHORT_DAY_NAME: &[&str] = &[
     "Sun",
     "Mon",
     "Tue",
@@ -317,7 +398,7 @@ pub const SHORT_DAY_NAME: &'static [&'static str] = &[
     "Sat"
 ];
 
-pub const LONG_DAY_NAME: &'static [&'static str] = &[
+pub const LONG_DAY_NAME: &[&str] = &[
     "Sunday",
     "Monday",
     "Tuesday",
@@ -327,7 +408,7 @@ pub const LONG_DAY_NAME: &'static [&'static str] = &[
     "Saturday"
 ];
 
-pub fn day_of_week(sdn: i32) -> i32 {
+pub const fn day_of_week(sdn: i32) -> i32 {
     let dow = (sdn + 1) % 7;
     if dow >= 0 {
         dow
@@ -335,3 +416,55 @@ pub fn day_of_week(sdn: i32) -> i32 {
         dow + 7
     }
 }
+
+impl CalendarDate for GregorianDate {
+    fn from_ymd(year: i32, month: i32, day: i32) -> Result<GregorianDate, CalendarError> {
+        GregorianDate::new(year, month, day)
+    }
+
+    fn year(&self) -> i32 {
+        self.year
+    }
+
+    fn month(&self) -> i32 {
+        self.month
+    }
+
+    fn day(&self) -> i32 {
+        self.day
+    }
+
+    fn to_sdn(&self) -> Result<Sdn, CalendarError> {
+        try_gregorian_to_sdn(self)
+    }
+
+    fn from_sdn(sdn: Sdn) -> Result<GregorianDate, CalendarError> {
+        try_sdn_to_gregorian(sdn)
+    }
+
+    fn valid_range() -> RangeInclusive<Sdn> {
+        Sdn::from_valid(FIRST_VALID)..=Sdn::from_valid(LAST_VALID)
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        month_length(year, 2) == 29
+    }
+
+    fn days_in_month(year: i32, month: i32) -> i32 {
+        if (1..=12).contains(&month) {
+            month_length(year, month)
+        } else {
+            0
+        }
+    }
+
+    fn month_name(_year: i32, month: i32) -> &'static str {
+        LONG_MONTH_NAME.get(month.max(0) as usize).copied().unwrap_or("")
+    }
+
+    fn next_day(&mut self) {
+        GregorianDate::next_day(self)
+    }
+}
+
+calendar::impl_date_arithmetic!(GregorianDate);
@@ -0,0 +1,117 @@
+/**************************************************************************
+ *
+ * This file is only compiled with the "chrono" or the "time" feature.
+ * These are the externally visible components of this file:
+ *
+ *     impl TryFrom<chrono::NaiveDate> for FrenchDate, GregorianDate,
+ *                                         JulianDate, JewishDate
+ *     impl TryFrom<FrenchDate, GregorianDate, JulianDate, JewishDate>
+ *                                         for chrono::NaiveDate
+ *
+ * Convert the dates to and from the dates of the chrono crate, with the
+ * "chrono" feature.  For example:
+ *
+ *    let naive_date = NaiveDate::from_ymd_opt(1799, 11, 9).unwrap();
+ *    let french_date = FrenchDate::try_from(naive_date)?;
+ *
+ *     impl TryFrom<time::Date> for FrenchDate, GregorianDate, JulianDate,
+ *                                  JewishDate
+ *     impl TryFrom<FrenchDate, GregorianDate, JulianDate, JewishDate>
+ *                                  for time::Date
+ *
+ * Convert the dates to and from the dates of the time crate, with the
+ * "time" feature.
+ *
+ * The conversions go through the SDN, so a date of any calendar can be
+ * converted to any date of the other crates.  They fail with
+ * CalendarError::OutOfRange when the date cannot be represented on the
+ * other side: a date before the 1st Vendémiaire an I converted to a
+ * FrenchDate, a date before the year 1 (3761 B.C.) converted to a
+ * JewishDate, or a date beyond the range of the other crate, e.g. after
+ * the year 262143 for chrono or 9999 for time.  The GregorianDate and
+ * JulianDate cover the whole range of both crates.  The zero date
+ * returned by the sdn_to_<CALENDAR>() routines is also rejected.
+ *
+ * The conversions are not implemented with From, even for a
+ * GregorianDate, because the ranges of the crates are different.
+ *
+ **************************************************************************/
+
+use crate::calendar::CalendarDate;
+use crate::error::CalendarError;
+use crate::french_calendar::FrenchDate;
+use crate::gregorian_calendar::GregorianDate;
+use crate::jewish_calendar::JewishDate;
+use crate::julian_calendar::JulianDate;
+use crate::sdn::Sdn;
+
+/* The SDN of the day before 1 January 1 A.D. in the Gregorian calendar,
+ * which is day 0 of chrono::NaiveDate::num_days_from_ce(). */
+#[cfg(feature = "chrono")]
+const CE_OFFSET: i64 = 1721425;
+
+#[cfg(feature = "chrono")]
+fn sdn_to_naive_date(sdn: Sdn) -> Result<chrono::NaiveDate, CalendarError> {
+    i32::try_from(sdn.value() - CE_OFFSET).ok()
+        .and_then(chrono::NaiveDate::from_num_days_from_ce_opt)
+        .ok_or(CalendarError::OutOfRange)
+}
+
+#[cfg(feature = "chrono")]
+fn naive_date_to_sdn(date: chrono::NaiveDate) -> Result<Sdn, CalendarError> {
+    use chrono::Datelike;
+    Sdn::new(date.num_days_from_ce() as i64 + CE_OFFSET)
+}
+
+/* The Julian day of the time crate is the SDN. */
+#[cfg(feature = "time")]
+fn sdn_to_time_date(sdn: Sdn) -> Result<time::Date, CalendarError> {
+    i32::try_from(sdn.value()).ok()
+        .and_then(|sdn| time::Date::from_julian_day(sdn).ok())
+        .ok_or(CalendarError::OutOfRange)
+}
+
+macro_rules! impl_date_interop {
+    ($date:ident) => {
+        #[cfg(feature = "chrono")]
+        impl TryFrom<chrono::NaiveDate> for $date {
+            type Error = CalendarError;
+
+            fn try_from(date: chrono::NaiveDate) -> Result<$date, CalendarError> {
+                $date::from_sdn(naive_date_to_sdn(date)?)
+            }
+        }
+
+        #[cfg(feature = "chrono")]
+        impl TryFrom<$date> for chrono::NaiveDate {
+            type Error = CalendarError;
+
+            fn try_from(date: $date) -> Result<chrono::NaiveDate, CalendarError> {
+                sdn_to_naive_date(date.to_sdn()?)
+            }
+        }
+
+        #[cfg(feature = "time")]
+        impl TryFrom<time::Date> for $date {
+            type Error = CalendarError;
+
+            fn try_from(date: time::Date) -> Result<$date, CalendarError> {
+                $date::from_sdn(Sdn::new(date.to_julian_day() as i64)?)
+            }
+        }
+
+        #[cfg(feature = "time")]
+        impl TryFrom<$date> for time::Date {
+            type Error = CalendarError;
+
+            fn try_from(date: $date) -> Result<time::Date, CalendarError> {
+                sdn_to_time_date(date.to_sdn()?)
+            }
+        }
+    };
+}
+
+impl_date_interop!(FrenchDate);
+impl_date_interop!(GregorianDate);
+impl_date_interop!(JulianDate);
+impl_date_interop!(JewishDate);
@@ -0,0 +1,675 @@
+/*
+ * This library is based on Scott E. Lee's work which is not more available.
+ *
+ * Copyright 1993-1995, Scott E. Lee, all rights reserved.
+ * Permission granted to use, copy, modify, distribute and sell so long as
+ * the above copyright and this permission statement are retained in all
+ * copies.  THERE IS NO WARRANTY - USE AT YOUR OWN RISK.
+ */
+
+/**************************************************************************
+ *
+ * These are the externally visible components of this file:
+ *
+ *     pub fn sdn_to_jewish(sdn: i32) -> JewishDate
+ *
+ * Convert a SDN to a Jewish calendar date.  If the input SDN is before the
+ * first day of year 1, the three output values will all be set to zero,
+ * otherwise *pYear will be > 0; *pMonth will be in the range 1 to 13
+ * inclusive; *pDay will be in the range 1 to 30 inclusive.  Note that
+ * Adar II is assigned the month number 7 and Elul is always 13.
+ *
+ *     pub fn jewish_to_sdn(jewish_date: &JewishDate) -> i32
+ *
+ * Convert a Jewish calendar date to a SDN.  Zero is returned when the
+ * input date is detected as invalid or out of the supported range.  The
+ * return value will be > 0 for all valid, supported dates.
+ *
+ *     pub fn try_sdn_to_jewish(sdn: Sdn) -> Result<JewishDate, CalendarError>
+ *     pub fn try_jewish_to_sdn(jewish_date: &JewishDate) -> Result<Sdn, CalendarError>
+ *
+ * Same as above, but the SDN is a Sdn (see sdn.rs) and the reason why a
+ * date or a SDN is rejected is returned instead of zero.  The routines
+ * above are thin wrappers of them.
+ *
+ *     pub fn JewishDate::new(year: i32, month: i32, day: i32) -> Result<JewishDate, CalendarError>
+ *
+ * Create a Jewish date, checking that it is valid and within the supported
+ * range.  Month 7 (Adar II) only exists in leap years, and the length of
+ * Heshvan and Kislev depends on the kind of the year.  The fields of a
+ * JewishDate are private and are read with the year(), month() and day()
+ * methods.
+ *
+ *     pub fn JewishDate::is_valid(&self) -> bool
+ *
+ * Tell whether the date is valid.
+ *
+ *     pub fn is_leap_year(year: i32) -> bool
+ *
+ * Tell whether the year has 13 months.
+ *
+ *     pub fn year_length(year: i32) -> i32
+ *
+ * Return the number of days of the year: 353, 354 or 355 for a common
+ * year and 383, 384 or 385 for a leap year.
+ *
+ *     pub enum YearKind
+ *     pub fn year_kind(year: i32) -> YearKind
+ *
+ * Tell whether the year is Deficient (Heshvan and Kislev have 29 days),
+ * Regular (Heshvan has 29 days and Kislev 30) or Complete (Heshvan and
+ * Kislev have 30 days).
+ *
+ *     pub const JEWISH_MONTH_NAME: &[&str]
+ *
+ * Convert a Jewish month number (1 to 13) to the name of the Jewish month
+ * as it is named in a leap year (null terminated).  An index of zero
+ * will return a zero length string.
+ *
+ *     pub fn month_name(year: i32, month: i32) -> &'static str
+ *
+ * Same as above, but month 6 is named Adar instead of Adar I in a common
+ * year.  An empty string is returned for a month that does not exist in
+ * the year.
+ *
+ * VALID RANGE
+ *
+ *     Although this software can handle dates all the way back to the year
+ *     1 (3761 B.C.), such use may not be meaningful.
+ *
+ *     The Jewish calendar has been in use for several thousand years, but
+ *     in the early days there was no formula to determine the start of a
+ *     month.  A new month was started when the new moon was first
+ *     observed.
+ *
+ *     It is not clear when the current rule based calendar replaced the
+ *     observation based calendar.  According to the book "Jewish Calendar
+ *     Mystery Dispelled" by George Zinberg, the patriarch Hillel II
+ *     published these rules in 358 A.D.  But, according to The
+ *     Encyclopedia Judaica, Hillel II may have only published the 19 year
+ *     rule for determining the occurrence of leap years.
+ *
+ *     I have yet to find a specific date when the current set of rules
+ *     were known to be in use.
+ *
+ * CALENDAR OVERVIEW
+ *
+ *     The Jewish calendar is based on lunar as well as solar cycles.  A
+ *     month always starts on or near a new moon and has either 29 or 30
+ *     days (a lunar cycle is about 29 1/2 days).  Twelve of these
+ *     alternating 29-30 day months gives a year of 354 days, which is
+ *     about 11 1/4 days short of a solar year.
+ *
+ *     Since a month is defined to be a lunar cycle (new moon to new moon),
+ *     this 11 1/4 day difference cannot be overcome by adding days to a
+ *     month as with the Gregorian calendar, so an entire month is
+ *     periodically added to the year, making some years 13 months long.
+ *
+ *     For astronomical as well as ceremonial reasons, the start of a new
+ *     year may be delayed until a day or two after the new moon causing
+ *     years to vary in length.  Leap years can be from 383 to 385 days
+ *     and common years can be from 353 to 355 days.  These are the months
+ *     of the year and their possible lengths:
+ *
+ *                       COMMON YEAR          LEAP YEAR
+ *          1 Tishri    30   30   30         30   30   30
+ *          2 Heshvan   29   29   30         29   29   30 (variable)
+ *          3 Kislev    29   30   30         29   30   30 (variable)
+ *          4 Tevet     29   29   29         29   29   29
+ *          5 Shevat    30   30   30         30   30   30
+ *          6 Adar I    29   29   29         30   30   30 (variable)
+ *          7 Adar II   --   --   --         29   29   29 (optional)
+ *          8 Nisan     30   30   30         30   30   30
+ *          9 Iyyar     29   29   29         29   29   29
+ *         10 Sivan     30   30   30         30   30   30
+ *         11 Tammuz    29   29   29         29   29   29
+ *         12 Av        30   30   30         30   30   30
+ *         13 Elul      29   29   29         29   29   29
+ *                     ---  ---  ---        ---  ---  ---
+ *                     353  354  355        383  384  385
+ *
+ *     Note that the month names and other words that appear in this file
+ *     have multiple possible spellings in the Roman character set.  I have
+ *     chosen to use the spellings found in the Encyclopedia Judaica.
+ *
+ *     Adar II, the month added for leap years, is sometimes referred to as
+ *     the 13th month, but I have chosen to assign it the number 7 to keep
+ *     the months in chronological order.  This may not be consistent with
+ *     other numbering schemes.
+ *
+ *     Leap years occur in a fixed pattern of 19 years called the metonic
+ *     cycle.  The 3rd, 6th, 8th, 11th, 14th, 17th and 19th years of this
+ *     cycle are leap years.  The first metonic cycle starts with Jewish
+ *     year 1, or 3761/60 B.C.  This is believed to be the year of
+ *     creation.
+ *
+ *     To construct the calendar for a year, you must first find the length
+ *     of the year by determining the first day of the year (Tishri 1, or
+ *     Rosh Ha-Shanah) and the first day of the following year.  This
+ *     selects one of the six possible month length configurations listed
+ *     above.
+ *
+ *     Finding the first day of the year is the most difficult part.
+ *     Finding the date and time of the new moon (or molad) is the first
+ *     step.  For this purpose, the lunar cycle is assumed to be 29 days 12
+ *     hours and 793 halakim.  A halakim is 1/1080th of an hour or 3 1/3
+ *     seconds.  (This assumed value is only about 1/2 second less than the
+ *     value used by modern astronomers -- not bad for a number that was
+ *     determined so long ago.)  The first molad of year 1 occurred on
+ *     Sunday at 11:20:11 P.M.  This would actually be Monday, because the
+ *     Jewish day is considered to begin at sunset.
+ *
+ *     Since sunset varies, the day is assumed to begin at 6:00 P.M.  for
+ *     calendar calculation purposes.  So, the first molad was 5 hours 793
+ *     halakim after the start of Tishri 1, 0001 (which was Monday
+ *     September 7, 4761 B.C. by the Gregorian calendar).  All subsequent
+ *     molads can be calculated from this starting point by adding the
+ *     length of a lunar cycle.
+ *
+ *     Once the molad that starts a year is determined the actual start of
+ *     the year (Tishri 1) can be determined.  Tishri 1 will be the day of
+ *     the molad unless it is delayed by one of the following four rules
+ *     (called dehiyyot).  Each rule can delay the start of the year by one
+ *     day, and since rule #1 can combine with one of the other rules, it
+ *     can be delayed as much as two days.
+ *
+ *         1.  Tishri 1 must never be Sunday, Wednesday or Friday.  (This
+ *             is largely to prevent certain holidays from occurring on the
+ *             day before or after the Sabbath.)
+ *
+ *         2.  If the molad occurs on or after noon, Tishri 1 must be
+ *             delayed.
+ *
+ *         3.  If it is a common (not leap) year and the molad occurs on
+ *             Tuesday at or after 3:11:20 A.M., Tishri 1 must be delayed.
+ *
+ *         4.  If it is the year following a leap year and the molad occurs
+ *             on Monday at or after 9:32:43 and 1/3 sec, Tishri 1 must be
+ *             delayed.
+ *
+ * REFERENCES
+ *
+ *     Encyclopedia Judaica, Keter Publishing House, 1972.
+ *
+ *     Jewish Calendar Mystery Dispelled by George Zinberg, Vantage Press,
+ *     1963.
+ *
+ **************************************************************************/
+
+use core::ops::RangeInclusive;
+
+use crate::calendar::{self, CalendarDate};
+use crate::error::CalendarError;
+use crate::sdn::Sdn;
+
+const HALAKIM_PER_HOUR: i64 = 1080;
+const HALAKIM_PER_DAY: i64 = 25920;
+const HALAKIM_PER_LUNAR_CYCLE: i64 = 29 * HALAKIM_PER_DAY + 13753;
+const HALAKIM_PER_METONIC_CYCLE: i64 = HALAKIM_PER_LUNAR_CYCLE * (12 * 19 + 7);
+
+const JEWISH_SDN_OFFSET: i64 = 347997;
+const NEW_MOON_OF_CREATION: i64 = 31524;
+
+const SUNDAY: i64 = 0;
+const MONDAY: i64 = 1;
+const TUESDAY: i64 = 2;
+const WEDNESDAY: i64 = 3;
+const FRIDAY: i64 = 5;
+
+const NOON: i64 = 18 * HALAKIM_PER_HOUR;
+const AM3_11_20: i64 = 9 * HALAKIM_PER_HOUR + 204;
+const AM9_32_43: i64 = 15 * HALAKIM_PER_HOUR + 589;
+
+const MONTHS_PER_YEAR: [i64; 19] = [
+    12, 12, 13, 12, 12, 13, 12, 13, 12, 12, 13, 12, 12, 13, 12, 12, 13, 12, 13,
+];
+
+const YEAR_OFFSET: [i64; 19] = [
+    0, 12, 24, 37, 49, 61, 74, 86, 99, 111, 123, 136, 148, 160, 173, 185, 197, 210, 222,
+];
+
+pub const JEWISH_MONTH_NAME: &[&str] = &[
+    "",
+    "Tishri",
+    "Heshvan",
+    "Kislev",
+    "Tevet",
+    "Shevat",
+    "Adar I",
+    "Adar II",
+    "Nisan",
+    "Iyyar",
+    "Sivan",
+    "Tammuz",
+    "Av",
+    "Elul",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum YearKind {
+    Deficient,
+    Regular,
+    Complete,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JewishDate {
+    year: i32,
+    month: i32,
+    day: i32,
+}
+
+impl JewishDate {
+    pub fn new(year: i32, month: i32, day: i32) -> Result<JewishDate, CalendarError> {
+        let jewish_date = JewishDate {day, month, year};
+        try_jewish_to_sdn(&jewish_date).map(|_| jewish_date)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        try_jewish_to_sdn(self).is_ok()
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> i32 {
+        self.month
+    }
+
+    pub fn day(&self) -> i32 {
+        self.day
+    }
+
+    pub fn next_day(&mut self) {
+        self.day += 1;
+        if self.day > month_length(self.year, self.month) {
+            self.day = 1;
+            self.month += 1;
+            if self.month == 7 && !is_leap_year(self.year) {
+                self.month += 1;
+            }
+            if self.month > 13 {
+                self.month = 1;
+                self.year += 1;
+            }
+        }
+    }
+}
+
+pub fn is_leap_year(year: i32) -> bool {
+    MONTHS_PER_YEAR[((year as i64 - 1).rem_euclid(19)) as usize] == 13
+}
+
+pub fn year_length(year: i32) -> i32 {
+    let tishri1 = find_start_of_year(year as i64).tishri1;
+    let tishri1_after = find_start_of_year(year as i64 + 1).tishri1;
+    (tishri1_after - tishri1) as i32
+}
+
+pub fn year_kind(year: i32) -> YearKind {
+    match year_length(year) % 10 {
+        3 => YearKind::Deficient,
+        4 => YearKind::Regular,
+        _ => YearKind::Complete,
+    }
+}
+
+pub fn month_name(year: i32, month: i32) -> &'static str {
+    match month {
+        6 if !is_leap_year(year) => "Adar",
+        7 if !is_leap_year(year) => "",
+        1..=13 => JEWISH_MONTH_NAME[month as usize],
+        _ => "",
+    }
+}
+
+fn month_length(year: i32, month: i32) -> i32 {
+    match month {
+        2 if year_kind(year) == YearKind::Complete => 30,
+        3 if year_kind(year) != YearKind::Deficient => 30,
+        6 if is_leap_year(year) => 30,
+        7 if !is_leap_year(year) => 0,
+        1 | 5 | 8 | 10 | 12 => 30,
+        _ => 29,
+    }
+}
+
+/* The molad (mean new moon) of Tishri of a year, counted in days and
+ * halakim from the start of the Jewish epoch. */
+struct Molad {
+    metonic_cycle: i64,
+    metonic_year: i64,
+    day: i64,
+    halakim: i64,
+}
+
+impl Molad {
+    fn add_halakim(&mut self, halakim: i64) {
+        self.halakim += halakim;
+        self.day += self.halakim.div_euclid(HALAKIM_PER_DAY);
+        self.halakim = self.halakim.rem_euclid(HALAKIM_PER_DAY);
+    }
+
+    /* Move to the molad of Tishri of the next year. */
+    fn next_year(&mut self) {
+        self.add_halakim(HALAKIM_PER_LUNAR_CYCLE * MONTHS_PER_YEAR[self.metonic_year as usize]);
+        self.metonic_year += 1;
+        if self.metonic_year == 19 {
+            self.metonic_year = 0;
+            self.metonic_cycle += 1;
+        }
+    }
+
+    /* Compute the first day of the year by applying the four dehiyyot to
+     * the day of the molad. */
+    fn tishri1(&self) -> i64 {
+        let mut tishri1 = self.day;
+        let mut dow = tishri1.rem_euclid(7);
+        let leap_year = matches!(self.metonic_year, 2 | 5 | 7 | 10 | 13 | 16 | 18);
+        let last_was_leap_year = matches!(self.metonic_year, 3 | 6 | 8 | 11 | 14 | 17 | 0);
+
+        /* Apply rules 2, 3 and 4. */
+        if self.halakim >= NOON
+            || (!leap_year && dow == TUESDAY && self.halakim >= AM3_11_20)
+            || (last_was_leap_year && dow == MONDAY && self.halakim >= AM9_32_43) {
+            tishri1 += 1;
+            dow += 1;
+            if dow == 7 {
+                dow = 0;
+            }
+        }
+
+        /* Apply rule 1 after the others because it can cause an additional
+         * delay of one day. */
+        if dow == WEDNESDAY || dow == FRIDAY || dow == SUNDAY {
+            tishri1 += 1;
+        }
+
+        tishri1
+    }
+}
+
+fn molad_of_metonic_cycle(metonic_cycle: i64) -> Molad {
+    /* Start with the time of the first molad after creation. */
+    let halakim = NEW_MOON_OF_CREATION + metonic_cycle * HALAKIM_PER_METONIC_CYCLE;
+    Molad {
+        metonic_cycle,
+        metonic_year: 0,
+        day: halakim.div_euclid(HALAKIM_PER_DAY),
+        halakim: halakim.rem_euclid(HALAKIM_PER_DAY),
+    }
+}
+
+/* Find the molad of Tishri closest to the given day. */
+fn find_tishri_molad(input_day: i64) -> Molad {
+    /* Estimate the metonic cycle number.  Note that this may be an under
+     * estimate because there are 6939.6896 days in a metonic cycle not
+     * 6940, but it will never be an over estimate.  The loop below will
+     * correct for any error in this estimate. */
+    let mut molad = molad_of_metonic_cycle((input_day + 310) / 6940);
+
+    /* If the above was an under estimate, increment the cycle number until
+     * the correct one is found.  For modern dates this loop is about 98.6%
+     * likely to not execute, even once, because the above estimate is
+     * really quite close. */
+    while molad.day < input_day - 6940 + 310 {
+        molad.metonic_cycle += 1;
+        molad.add_halakim(HALAKIM_PER_METONIC_CYCLE);
+    }
+
+    /* Find the molad of Tishri closest to this date. */
+    while molad.metonic_year < 18 && molad.day <= input_day - 74 {
+        molad.next_year();
+    }
+
+    molad
+}
+
+struct StartOfYear {
+    molad: Molad,
+    tishri1: i64,
+}
+
+fn find_start_of_year(year: i64) -> StartOfYear {
+    let mut molad = molad_of_metonic_cycle((year - 1).div_euclid(19));
+    molad.metonic_year = (year - 1).rem_euclid(19);
+    molad.add_halakim(HALAKIM_PER_LUNAR_CYCLE * YEAR_OFFSET[molad.metonic_year as usize]);
+
+    let tishri1 = molad.tishri1();
+    StartOfYear {molad, tishri1}
+}
+
+pub fn sdn_to_jewish(sdn: i32) -> JewishDate {
+    Sdn::new(sdn as i64).and_then(try_sdn_to_jewish).unwrap_or(JewishDate {
+        day: 0,
+        month: 0,
+        year: 0,
+    })
+}
+
+pub fn jewish_to_sdn(jewish_date: &JewishDate) -> i32 {
+    try_jewish_to_sdn(jewish_date).map_or(0, |sdn| sdn.value() as i32)
+}
+
+pub fn try_sdn_to_jewish(sdn: Sdn) -> Result<JewishDate, CalendarError> {
+    if sdn.value() <= JEWISH_SDN_OFFSET {
+        return Err(CalendarError::OutOfRange);
+    }
+    let input_day = sdn.value() - JEWISH_SDN_OFFSET;
+
+    let mut molad = find_tishri_molad(input_day);
+    let mut tishri1 = molad.tishri1();
+    let tishri1_after;
+    let year;
+
+    let date = |year: i64, month, day: i64| JewishDate {
+        year: year as i32,
+        month,
+        day: day as i32,
+    };
+
+    if input_day >= tishri1 {
+        /* It found Tishri 1 at the start of the year. */
+        year = molad.metonic_cycle * 19 + molad.metonic_year + 1;
+        if input_day < tishri1 + 59 {
+            if input_day < tishri1 + 30 {
+                return Ok(date(year, 1, input_day - tishri1 + 1));
+            } else {
+                return Ok(date(year, 2, input_day - tishri1 - 29));
+            }
+        }
+
+        /* We need the length of the year to figure this out, so find
+         * Tishri 1 of the next year. */
+        molad.next_year();
+        tishri1_after = molad.tishri1();
+    } else {
+        /* It found Tishri 1 at the end of the year. */
+        year = molad.metonic_cycle * 19 + molad.metonic_year;
+        if input_day >= tishri1 - 177 {
+            /* It is one of the last 6 months of the year. */
+            let (month, offset) = if input_day > tishri1 - 30 {
+                (13, 30)
+            } else if input_day > tishri1 - 60 {
+                (12, 60)
+            } else if input_day > tishri1 - 89 {
+                (11, 89)
+            } else if input_day > tishri1 - 119 {
+                (10, 119)
+            } else if input_day > tishri1 - 148 {
+                (9, 148)
+            } else {
+                (8, 178)
+            };
+            return Ok(date(year, month, input_day - tishri1 + offset));
+        }
+
+        /* Walk back from Adar II (or Adar in a common year) to Tevet. */
+        let (mut month, lengths): (i32, &[i64]) = if MONTHS_PER_YEAR[((year - 1) % 19) as usize] == 13 {
+            (7, &[30, 30, 29])
+        } else {
+            (6, &[30, 29])
+        };
+        let mut day = input_day - tishri1 + 207;
+        for length in lengths {
+            if day > 0 {
+                return Ok(date(year, month, day));
+            }
+            month -= 1;
+            day += length;
+        }
+        if day > 0 {
+            return Ok(date(year, month, day));
+        }
+
+        /* We need the length of the year to figure this out, so find
+         * Tishri 1 of this year. */
+        tishri1_after = tishri1;
+        molad = find_tishri_molad(molad.day - 365);
+        tishri1 = molad.tishri1();
+    }
+
+    let year_length = tishri1_after - tishri1;
+    let mut day = input_day - tishri1 - 29;
+    let heshvan_length = if year_length == 355 || year_length == 385 {
+        30
+    } else {
+        29
+    };
+    if day <= heshvan_length {
+        return Ok(date(year, 2, day));
+    }
+    day -= heshvan_length;
+
+    /* It has to be Kislev. */
+    Ok(date(year, 3, day))
+}
+
+pub fn try_jewish_to_sdn(jewish_date: &JewishDate) -> Result<Sdn, CalendarError> {
+    let year = jewish_date.year as i64;
+    let day = jewish_date.day as i64;
+
+    /* check for invalid dates */
+    if jewish_date.year <= 0 {
+        return Err(CalendarError::OutOfRange);
+    } else if jewish_date.month <= 0
+        || jewish_date.month > 13
+        || (jewish_date.month == 7 && !is_leap_year(jewish_date.year)) {
+        return Err(CalendarError::InvalidMonth);
+    } else if jewish_date.day <= 0
+        || jewish_date.day > month_length(jewish_date.year, jewish_date.month) {
+        return Err(CalendarError::InvalidDay);
+    }
+
+    let sdn = match jewish_date.month {
+        1 | 2 => {
+            /* It is Tishri or Heshvan - don't need the year length. */
+            let tishri1 = find_start_of_year(year).tishri1;
+            if jewish_date.month == 1 {
+                tishri1 + day - 1
+            } else {
+                tishri1 + day + 29
+            }
+        }
+        3 => {
+            /* It is Kislev - must find the year length. */
+            let mut start = find_start_of_year(year);
+            start.molad.next_year();
+            let year_length = start.molad.tishri1() - start.tishri1;
+            if year_length == 355 || year_length == 385 {
+                start.tishri1 + day + 59
+            } else {
+                start.tishri1 + day + 58
+            }
+        }
+        4..=6 => {
+            /* It is Tevet, Shevat or Adar I - don't need the year length. */
+            let tishri1_after = find_start_of_year(year + 1).tishri1;
+            let length_of_adar_i_and_ii = if is_leap_year(jewish_date.year) {
+                59
+            } else {
+                29
+            };
+            let offset = match jewish_date.month {
+                4 => 237,
+                5 => 208,
+                _ => 178,
+            };
+            tishri1_after + day - length_of_adar_i_and_ii - offset
+        }
+        month => {
+            /* It is Adar II or later - don't need the year length. */
+            let tishri1_after = find_start_of_year(year + 1).tishri1;
+            let offset = match month {
+                7 => 207,
+                8 => 178,
+                9 => 148,
+                10 => 119,
+                11 => 89,
+                12 => 60,
+                _ => 30,
+            };
+            tishri1_after + day - offset
+        }
+    };
+
+    Sdn::new(sdn + JEWISH_SDN_OFFSET)
+}
+
+impl CalendarDate for JewishDate {
+    fn from_ymd(year: i32, month: i32, day: i32) -> Result<JewishDate, CalendarError> {
+        JewishDate::new(year, month, day)
+    }
+
+    fn year(&self) -> i32 {
+        self.year
+    }
+
+    fn month(&self) -> i32 {
+        self.month
+    }
+
+    fn day(&self) -> i32 {
+        self.day
+    }
+
+    fn to_sdn(&self) -> Result<Sdn, CalendarError> {
+        try_jewish_to_sdn(self)
+    }
+
+    fn from_sdn(sdn: Sdn) -> Result<JewishDate, CalendarError> {
+        try_sdn_to_jewish(sdn)
+    }
+
+    fn valid_range() -> RangeInclusive<Sdn> {
+        Sdn::from_valid(JEWISH_SDN_OFFSET + 1)..=Sdn::MAX
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        is_leap_year(year)
+    }
+
+    fn days_in_month(year: i32, month: i32) -> i32 {
+        if year > 0 && (1..=13).contains(&month) {
+            month_length(year, month)
+        } else {
+            0
+        }
+    }
+
+    fn month_name(year: i32, month: i32) -> &'static str {
+        month_name(year, month)
+    }
+
+    fn months_before_year(year: i32) -> i64 {
+        let cycles = (year as i64 - 1).div_euclid(19);
+        cycles * (12 * 19 + 7) + YEAR_OFFSET[(year as i64 - 1).rem_euclid(19) as usize]
+    }
+
+    fn next_day(&mut self) {
+        JewishDate::next_day(self)
+    }
+}
+
+calendar::impl_date_arithmetic!(JewishDate);
@@ -0,0 +1,341 @@
+/*
+ * This library is based on Scott E. Lee's work which is not more available.
+ *
+ * Copyright 1993-1995, Scott E. Lee, all rights reserved.
+ * Permission granted to use, copy, modify, distribute and sell so long as
+ * the above copyright and this permission statement are retained in all
+ * copies.  THERE IS NO WARRANTY - USE AT YOUR OWN RISK.
+ */
+
+/**************************************************************************
+ *
+ * These are the externally visible components of this file:
+ *
+ *     pub const fn sdn_to_julian(sdn: i32) -> JulianDate
+ *
+ * Convert a SDN to a Julian calendar date.  If the input SDN is less than
+ * 1, the three output values will all be set to zero, otherwise *pYear
+ * will be >= -4713 and != 0; *pMonth will be in the range 1 to 12
+ * inclusive; *pDay will be in the range 1 to 31 inclusive.  Use
+ * try_sdn_to_julian() for the SDNs less than 1.
+ *
+ *     pub const fn julian_to_sdn(julian_date: &JulianDate) -> i32
+ *
+ * Convert a Julian calendar date to a SDN.  Zero is returned when the
+ * input date is detected as invalid or out of the supported range.  The
+ * return value will be > 0 for all valid, supported dates, so the dates
+ * before January 2, 4713 B.C. are rejected.
+ *
+ *     pub const fn try_sdn_to_julian(sdn: Sdn) -> Result<JulianDate, CalendarError>
+ *     pub const fn try_julian_to_sdn(julian_date: &JulianDate) -> Result<Sdn, CalendarError>
+ *
+ * Same as above, but the SDN is a Sdn (see sdn.rs) and the reason why a
+ * date or a SDN is rejected is returned instead of zero.  These routines
+ * also handle the SDNs less than 1, down to January 1, 1000000 B.C.  The
+ * routines above are thin wrappers of them.
+ *
+ *     pub const fn JulianDate::new(year: i32, month: i32, day: i32) -> Result<JulianDate, CalendarError>
+ *
+ * Create a Julian date, checking that it is valid and within the
+ * supported range.  The fields of a JulianDate are private, so a date is
+ * always valid, except the zero date returned by sdn_to_julian() for an
+ * invalid SDN.  They are read with the year(), month() and day() methods.
+ *
+ *     pub const fn JulianDate::is_valid(&self) -> bool
+ *
+ * Tell whether the date is valid.
+ *
+ * The names of the months are the same as in the Gregorian calendar, see
+ * gregorian_calendar::SHORT_MONTH_NAME and LONG_MONTH_NAME.
+ *
+ * VALID RANGE
+ *
+ *     1000000 B.C. to 1000000 A.D., 4713 B.C. to 1000000 A.D. for the
+ *     routines returning zero for an invalid date.
+ *
+ *     Although this software can handle dates all the way back to 1000000
+ *     B.C., such use may not be meaningful.  The calendar was created in
+ *     46 B.C., but the details did not stabilize until at least 8 A.D.,
+ *     and perhaps as late at the 4th century.  Also, the beginning of a
+ *     year varied from one culture to another - not all accepted January
+ *     as the first month.
+ *
+ *     Dates before the year 1000000 B.C. or after the year 1000000 A.D.
+ *     are rejected as out of range.  The calculations are done on 64 bits,
+ *     so that they do not overflow.
+ *
+ * CALENDAR OVERVIEW
+ *
+ *     Julius Caesar created the calendar in 46 B.C. as a modified form of
+ *     the old Roman republican calendar which was based on lunar cycles.
+ *     The new Julian calendar set fixed lengths for the months, abandoning
+ *     the lunar cycle.  It also specified that there would be exactly 12
+ *     months per year and 365.25 days per year with every 4th year being a
+ *     leap year.
+ *
+ *     Note that the current accepted value for the tropical year is
+ *     365.242199 days, not 365.25.  This lead to an 11 day shift in the
+ *     calendar with respect to the seasons by the 16th century when the
+ *     Gregorian calendar was created to replace the Julian calendar.
+ *
+ *     The difference between the Julian and today's Gregorian calendar is
+ *     that the Gregorian does not make centennial years leap years unless
+ *     they are a multiple of 400, which leads to a year of 365.2425 days.
+ *     In other words, in the Gregorian calendar, 1700, 1800 and 1900 are
+ *     not leap years, but 2000 is.  All centennial years are leap years in
+ *     the Julian calendar.
+ *
+ *     The Gregorian calendar was adopted in October 1582 in Catholic
+ *     countries, but much later elsewhere: in 1752 in Britain and its
+ *     colonies, in 1918 in Russia and in 1923 in Greece.
+ *
+ * ALGORITHMS
+ *
+ *     The calculations are based on two different cycles: a 4 year cycle
+ *     of leap years and a 5 month cycle of month lengths.  See
+ *     gregorian_calendar for the description of the 5 month cycle.  As in
+ *     the Gregorian calendar, the calculations (internally) assume that
+ *     the year starts with March 1, and the years are shifted by 1000000
+ *     years so that the calculations only work on positive numbers.
+ *
+ * TESTING
+ *
+ *     This algorithm has been tested from the year 4713 B.C. to 10000
+ *     A.D.  The source code of the verification program is included in
+ *     this package.
+ *
+ * REFERENCES
+ *
+ *     Conversions Between Calendar Date and Julian Day Number by Robert J.
+ *     Tantzen, Communications of the Association for Computing Machinery
+ *     August 1963.  (Also published in Collected Algorithms from CACM,
+ *     algorithm number 199).
+ *
+ **************************************************************************/
+
+use core::ops::RangeInclusive;
+
+use crate::calendar::{self, CalendarDate};
+use crate::error::CalendarError;
+use crate::gregorian_calendar::LONG_MONTH_NAME;
+use crate::sdn::Sdn;
+
+const SDN_OFFSET: i64 = 32083;
+const DAYS_PER_5_MONTHS: i64 = 153;
+const DAYS_PER_4_YEARS: i64 = 1461;
+const SHIFT_CYCLES: i64 = 250000;
+const FIRST_YEAR: i32 = -1000000;
+const LAST_YEAR: i32 = 1000000;
+const FIRST_VALID: i64 = -363528576; /* January 1, 1000000 B.C. */
+const LAST_VALID: i64 = 366971423; /* December 31, 1000000 */
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JulianDate {
+    year: i32,
+    month: i32,
+    day: i32,
+}
+
+impl JulianDate {
+    pub const fn new(year: i32, month: i32, day: i32) -> Result<JulianDate, CalendarError> {
+        let julian_date = JulianDate {day, month, year};
+        match try_julian_to_sdn(&julian_date) {
+            Ok(_) => Ok(julian_date),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub const fn is_valid(&self) -> bool {
+        try_julian_to_sdn(self).is_ok()
+    }
+
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub const fn month(&self) -> i32 {
+        self.month
+    }
+
+    pub const fn day(&self) -> i32 {
+        self.day
+    }
+
+    pub fn next_day(&mut self) {
+        self.day += 1;
+        if self.day > month_length(self.year, self.month) {
+            self.day = 1;
+            self.month += 1;
+            if self.month > 12 {
+                self.month = 1;
+                self.year += 1;
+                if self.year == 0 {
+                    self.year += 1;
+                }
+            }
+        }
+    }
+}
+
+const fn month_length(year: i32, month: i32) -> i32 {
+    const MONTH_LENGTH: [i32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    if month != 2 {
+        return MONTH_LENGTH[month as usize - 1];
+    }
+
+    let mut y = year;
+    if y < 0 {
+        y += 1;
+    }
+    y += 4800;
+
+    if y % 4 == 0 {
+        29
+    } else {
+        28
+    }
+}
+
+pub const fn sdn_to_julian(sdn: i32) -> JulianDate {
+    let result = match Sdn::new(sdn as i64) {
+        Ok(sdn) => try_sdn_to_julian(sdn),
+        Err(error) => Err(error),
+    };
+    match result {
+        Ok(julian_date) if sdn > 0 => julian_date,
+        _ => JulianDate {
+            day: 0,
+            month: 0,
+            year: 0,
+        },
+    }
+}
+
+pub const fn julian_to_sdn(julian_date: &JulianDate) -> i32 {
+    match try_julian_to_sdn(julian_date) {
+        Ok(sdn) if sdn.value() > 0 => sdn.value() as i32,
+        _ => 0,
+    }
+}
+
+pub const fn try_sdn_to_julian(sdn: Sdn) -> Result<JulianDate, CalendarError> {
+    let sdn = sdn.value();
+    if sdn < FIRST_VALID || sdn > LAST_VALID {
+        Err(CalendarError::OutOfRange)
+    } else {
+        let mut temp: i64 = (sdn + SDN_OFFSET + SHIFT_CYCLES * DAYS_PER_4_YEARS) * 4 - 1;
+
+        /* Calculate the year and day of year (1 <= day_of_year <= 366). */
+        let mut year: i64 = temp / DAYS_PER_4_YEARS;
+        let day_of_year: i64 = (temp % DAYS_PER_4_YEARS) / 4 + 1;
+
+        /* Calculate the month and day of month. */
+        temp = day_of_year * 5 - 3;
+        let mut month: i64 = temp / DAYS_PER_5_MONTHS;
+        let day: i64 = (temp % DAYS_PER_5_MONTHS) / 5 + 1;
+
+        /* Convert to the normal beginning of the year. */
+        if month < 10 {
+            month += 3;
+        } else {
+            year += 1;
+            month -= 9;
+        }
+
+        /* Adjust to the B.C./A.D. type numbering. */
+        year -= 4800 + SHIFT_CYCLES * 4;
+        if year <= 0 {
+            year -= 1;
+        }
+
+        Ok(JulianDate {year: year as i32, month: month as i32, day: day as i32})
+    }
+}
+
+pub const fn try_julian_to_sdn(julian_date: &JulianDate) -> Result<Sdn, CalendarError> {
+    /* check for invalid dates */
+    if julian_date.year == 0 {
+        Err(CalendarError::YearZero)
+    } else if julian_date.year < FIRST_YEAR || julian_date.year > LAST_YEAR {
+        Err(CalendarError::OutOfRange)
+    } else if julian_date.month <= 0 || julian_date.month > 12 {
+        Err(CalendarError::InvalidMonth)
+    } else if julian_date.day <= 0
+        || julian_date.day > month_length(julian_date.year, julian_date.month) {
+        Err(CalendarError::InvalidDay)
+    } else {
+        /* Make year always a positive number. */
+        let mut year: i64 = julian_date.year as i64 + 4800 + SHIFT_CYCLES * 4;
+        if julian_date.year < 0 {
+            year += 1;
+        }
+
+        /* Adjust the start of the year. */
+        let month: i64;
+        if julian_date.month > 2 {
+            month = julian_date.month as i64 - 3;
+        } else {
+            month = julian_date.month as i64 + 9;
+            year -= 1;
+        }
+
+        let sdn = (year * DAYS_PER_4_YEARS) / 4
+            + (month * DAYS_PER_5_MONTHS + 2) / 5
+            + julian_date.day as i64
+            - SDN_OFFSET
+            - SHIFT_CYCLES * DAYS_PER_4_YEARS;
+        Sdn::new(sdn)
+    }
+}
+
+impl CalendarDate for JulianDate {
+    fn from_ymd(year: i32, month: i32, day: i32) -> Result<JulianDate, CalendarError> {
+        JulianDate::new(year, month, day)
+    }
+
+    fn year(&self) -> i32 {
+        self.year
+    }
+
+    fn month(&self) -> i32 {
+        self.month
+    }
+
+    fn day(&self) -> i32 {
+        self.day
+    }
+
+    fn to_sdn(&self) -> Result<Sdn, CalendarError> {
+        try_julian_to_sdn(self)
+    }
+
+    fn from_sdn(sdn: Sdn) -> Result<JulianDate, CalendarError> {
+        try_sdn_to_julian(sdn)
+    }
+
+    fn valid_range() -> RangeInclusive<Sdn> {
+        Sdn::from_valid(FIRST_VALID)..=Sdn::from_valid(LAST_VALID)
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        month_length(year, 2) == 29
+    }
+
+    fn days_in_month(year: i32, month: i32) -> i32 {
+        if (1..=12).contains(&month) {
+            month_length(year, month)
+        } else {
+            0
+        }
+    }
+
+    fn month_name(_year: i32, month: i32) -> &'static str {
+        LONG_MONTH_NAME.get(month.max(0) as usize).copied().unwrap_or("")
+    }
+
+    fn next_day(&mut self) {
+        JulianDate::next_day(self)
+    }
+}
+
+calendar::impl_date_arithmetic!(JulianDate);
@@ -1,5 +1,37 @@
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod astronomy;
+pub mod calendar;
+pub mod clock;
+pub mod day_count;
+pub mod decimal_time;
+pub mod error;
+#[cfg(feature = "alloc")]
+pub mod format;
 pub mod french_calendar;
 pub mod gregorian_calendar;
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod interop;
+pub mod jewish_calendar;
+pub mod julian_calendar;
+pub mod literal;
+pub mod locale;
+#[cfg(feature = "alloc")]
+pub mod parse;
+pub mod range;
+pub mod roman;
+pub mod sdn;
+#[cfg(feature = "serde")]
+pub mod serialization;
+
+pub use calendar::{convert, CalendarDate, ClampPolicy, Days};
+pub use error::CalendarError;
+pub use sdn::Sdn;
 
 /*
  * This library is based on Scott E. Lee's work which is not more available.
@@ -40,24 +72,102 @@ pub mod gregorian_calendar;
  * <CALENDAR> is the name of the calendar system.
  *
  * SDN values less than one are not supported.  If a conversion routine
- * returns an SDN of zero, this means that the date given is either invalid
- * or is outside the supported range for that calendar.
+ * returns an SDN of zero, this means that the date given is outside the
+ * supported range for that calendar.
+ *
+ * Each calendar also provides the routines try_sdn_to_<CALENDAR>() and
+ * try_<CALENDAR>_to_sdn(), which take and return the SDN as a Sdn, a SDN
+ * on 64 bits with checked arithmetic (see sdn.rs), and return a Result
+ * instead of zero.  The error is a CalendarError telling why the date was
+ * rejected: out of range, invalid month, invalid day for this month, 6th
+ * complementary day of a non-sextile French republican year or year zero.
+ * These routines also handle the SDNs less than one for the Gregorian and
+ * Julian calendars, down to the year 1000000 B.C., and the routines
+ * returning zero are thin wrappers of them.  The Sdn is converted to and
+ * from the other day counts, the fractional Julian Date, the Modified
+ * Julian Date, the Rata Die, the Unix time and the serial dates of the
+ * spreadsheets, in day_count.rs.  FrenchDate::today() and
+ * GregorianDate::today() give the current date at an offset from UTC,
+ * such as the Paris Mean Time, from the system clock or from a given
+ * clock (see clock.rs).
+ *
+ * Dates are created with the checked constructors FrenchDate::new(),
+ * GregorianDate::new(), JulianDate::new() and JewishDate::new(), which
+ * return a CalendarError for an invalid date.  The fields of the dates
+ * are private, so a date that exists is valid and there is no need to
+ * convert it to SDN and back to check it.  The only exception is the zero
+ * date returned by sdn_to_<CALENDAR>() for an SDN out of range, for which
+ * is_valid() returns false.
  *
- * At least some validity checks are performed on input dates.  For
- * example, a negative month number will result in the return of zero for
- * the SDN.  A returned SDN greater than one does not necessarily mean that
- * the input date was valid.  To determine if the date is valid, convert it
- * to SDN, and if the SDN is greater than zero, convert it back to a date
- * and compare to the original.  For example:
+ * Up to version 0.1 of the package, the fields of FrenchDate and
+ * GregorianDate were public.  Making them private is an incompatible
+ * change, hence version 0.2, for the code that built or read them
+ * directly, which is migrated as follows:
  *
- *    use repub_cli::{french_calendar, gregorian_calendar};
+ *    FrenchDate {year: 8, month: 2, day: 18}   FrenchDate::new(8, 2, 18)?
+ *    french_date.year                          french_date.year()
+ *    french_date.month = 3                     FrenchDate::new(french_date.year(), 3,
+ *                                                              french_date.day())?
+ *
+ * For example:
+ *
+ *    use french_republican_calendar::{gregorian_calendar::GregorianDate, CalendarError};
  *    ...
- *    let sdn = gregorian_calendar::gregorian_to_sdn(GregorianDate{year: y1, month: m1, day: d1});
- *    if sdn > 0 {
- *        let gregorian_date = gregorian_calendar::sdn_to_gregorian(sdn);
- *        if y1 == gregorian_date.year && m1 == gregorian_date.month && d1 == gregorian_date.day {
- *            ... date is valid ...
- *        }
+ *    match GregorianDate::new(1900, 2, 29) {
+ *        Ok(gregorian_date) => ... date is valid ...
+ *        Err(CalendarError::InvalidDay) => ... no 29 February in 1900 ...
+ *        Err(error) => ...
  *    }
  *
+ * All the dates implement the CalendarDate trait, which gives the same
+ * interface to every calendar, so that generic code can handle any of
+ * them.  The convert() routine converts a date from any calendar to any
+ * other through its SDN:
+ *
+ *    use french_republican_calendar::{convert, french_calendar::FrenchDate,
+ *                                     gregorian_calendar::GregorianDate};
+ *    ...
+ *    let french_date: FrenchDate = convert(&GregorianDate::new(1799, 11, 9)?)?;
+ *
+ * The trait also provides the date arithmetic, add_days(), days_until(),
+ * add_months() and add_years(), and the dates implement the + and -
+ * operators with a number of Days.  The dates are sorted in chronological
+ * order.
+ *
+ * The conversions of the French republican, Gregorian and Julian dates
+ * are const fn, and the french_date! and gregorian_date! macros give a
+ * date written in a string literal, checked at compile time (see
+ * literal.rs):
+ *
+ *    const COUP_D_ETAT: FrenchDate = french_date!("18 Brumaire VIII");
+ *    const SDN: i32 = french_to_sdn(&COUP_D_ETAT);
+ *
+ * The Jewish calendar and the astronomical leap year rule of the French
+ * republican calendar are not const.
+ *
+ * With the "serde" feature, the dates can be serialized and deserialized
+ * with serde, either as a structure or as a compact string (see
+ * serialization.rs).
+ *
+ * With the "chrono" and "time" features, the dates can be converted to and
+ * from chrono::NaiveDate and time::Date with TryFrom (see interop.rs).
+ *
+ * The library is no_std: the conversions and the date arithmetic only
+ * need the core library, so that they can be used on embedded devices.
+ * The cargo features enable the rest:
+ *
+ *     alloc     the formatting of dates (format.rs), including Display
+ *               for the dates, the parsing of dates written in words
+ *               (parse.rs) and roman::to_roman().
+ *     std       the standard library, implies alloc, the system clock
+ *               giving today's date and the repub command line
+ *               converter (src/bin/repub.rs).  It is a default feature.
+ *     gui       the fltk application, which is the main binary of the
+ *               package.  It implies std.
+ *     serde, chrono, time
+ *               the interoperability with these crates, see above.
+ *
+ * For example, a library user who does not need the formatting depends
+ * on the package with default-features = false.
+ *
  **************************************************************************/
@@ -0,0 +1,359 @@
+/**************************************************************************
+ *
+ * These are the externally visible components of this file:
+ *
+ *     french_date!(text)
+ *     gregorian_date!(text)
+ *
+ * Give the FrenchDate or the GregorianDate written in the text, checked at
+ * compile time: a malformed or invalid date fails the build with the
+ * reason of the error, so that known dates, such as historical fixtures,
+ * can be constants.  For example:
+ *
+ *    use french_republican_calendar::{french_date, gregorian_date};
+ *    ...
+ *    const COUP_D_ETAT: FrenchDate = french_date!("18 Brumaire VIII");
+ *    const SAME_DAY: GregorianDate = gregorian_date!("1799-11-09");
+ *
+ *     pub const fn parse_french(text: &str) -> Result<FrenchDate, LiteralError>
+ *     pub const fn parse_gregorian(text: &str) -> Result<GregorianDate, LiteralError>
+ *
+ * The parsers used by the macros, which can also be called at run time.
+ *
+ * parse_french() accepts "<day> <month> [an] <year>": the day in Arabic
+ * numerals, the full name of the month, ignoring the case and the
+ * accents, or for the complementary days "Extra", "sansculottide(s)" or
+ * "complémentaire(s)", and the year in Arabic numerals or in Roman
+ * numerals, read as the parsing of parse.rs does (IIII being accepted for
+ * 4, and only I to XCIX without "an").  The date is checked under the
+ * default leap year rule.  The parsing of parse.rs accepts many more
+ * forms, but it is not const and needs the "alloc" feature.
+ *
+ * parse_gregorian() accepts "YYYY-MM-DD", as serialization::compact: the
+ * year has at least 4 digits and is astronomical as in ISO 8601, 0 being
+ * 1 B.C., and it may be signed, e.g. "-0043-03-15" for the Ides of March
+ * of 44 B.C.  A year too large for the calendar, even beyond the range of
+ * an i32, is Date(CalendarError::OutOfRange).
+ *
+ *     pub enum LiteralError
+ *
+ * The reasons why the text of a literal can be rejected:
+ *
+ *     InvalidDay        the day is not a number (parse_french()).
+ *     UnknownMonth      the month is not a known name (parse_french()).
+ *     MissingYear       the year is missing (parse_french()).
+ *     InvalidYear       the year is not a number (parse_french()).
+ *     UnexpectedWord    there is a word after the year (parse_french()).
+ *     Malformed         the text is not written YYYY-MM-DD
+ *                       (parse_gregorian()).
+ *     Date(error)       the text is well written, but the date does not
+ *                       exist or is out of range.
+ *
+ *     pub const fn LiteralError::message(self) -> &'static str
+ *
+ * Return the message displayed by the error, which is the one of the
+ * CalendarError for Date(error).  It is const, so that the macros can
+ * fail the build with it.
+ *
+ **************************************************************************/
+
+use core::fmt;
+
+use crate::error::CalendarError;
+use crate::french_calendar::{FrenchDate, FRENCH_MONTH_NAME};
+use crate::gregorian_calendar::GregorianDate;
+use crate::roman;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LiteralError {
+    InvalidDay,
+    UnknownMonth,
+    MissingYear,
+    InvalidYear,
+    UnexpectedWord,
+    Malformed,
+    Date(CalendarError),
+}
+
+impl LiteralError {
+    pub const fn message(self) -> &'static str {
+        match self {
+            LiteralError::InvalidDay => "invalid day",
+            LiteralError::UnknownMonth => "unknown month",
+            LiteralError::MissingYear => "missing year",
+            LiteralError::InvalidYear => "invalid year",
+            LiteralError::UnexpectedWord => "unexpected word after the year",
+            LiteralError::Malformed => "malformed date, expected YYYY-MM-DD",
+            LiteralError::Date(error) => error.message(),
+        }
+    }
+}
+
+impl fmt::Display for LiteralError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for LiteralError {}
+
+/* The other names of the complementary days, "Extra" being the 13th name of
+ * FRENCH_MONTH_NAME. */
+const COMPLEMENTARY_NAME: [&str; 4] = [
+    "sansculottide",
+    "sansculottides",
+    "complémentaire",
+    "complémentaires",
+];
+
+/* A word of the text, as byte positions. */
+#[derive(Clone, Copy)]
+struct Word {
+    start: usize,
+    end: usize,
+}
+
+const fn is_separator(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\r' | b',')
+}
+
+/* The next word after the position, which is empty at the end of the text. */
+const fn next_word(bytes: &[u8], position: usize) -> Word {
+    let mut start = position;
+    while start < bytes.len() && is_separator(bytes[start]) {
+        start += 1;
+    }
+    let mut end = start;
+    while end < bytes.len() && !is_separator(bytes[end]) {
+        end += 1;
+    }
+    Word {start, end}
+}
+
+/* The number written in decimal digits, None if it is not or if it
+ * overflows. */
+const fn parse_number(bytes: &[u8], word: Word) -> Option<i32> {
+    if word.start == word.end {
+        return None;
+    }
+    let mut number: i32 = 0;
+    let mut i = word.start;
+    while i < word.end {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+        number = match number.checked_mul(10) {
+            Some(tens) => match tens.checked_add((bytes[i] - b'0') as i32) {
+                Some(number) => number,
+                None => return None,
+            },
+            None => return None,
+        };
+        i += 1;
+    }
+    Some(number)
+}
+
+/* The character at the position, as a lower case ASCII letter without
+ * accent, and its length in bytes.  The letter is 0 for an unknown
+ * accented letter. */
+const fn fold_at(bytes: &[u8], i: usize) -> (u8, usize) {
+    if bytes[i] != 0xc3 || i + 1 >= bytes.len() {
+        return (bytes[i].to_ascii_lowercase(), 1);
+    }
+    /* The Latin-1 letters, the upper case ones set to lower case. */
+    let letter = match bytes[i + 1] | 0x20 {
+        0xa0..=0xa5 => b'a',
+        0xa7 => b'c',
+        0xa8..=0xab => b'e',
+        0xac..=0xaf => b'i',
+        0xb2..=0xb6 => b'o',
+        0xb9..=0xbc => b'u',
+        _ => 0,
+    };
+    (letter, 2)
+}
+
+/* Whether the word is the name, ignoring the case and the accents. */
+const fn word_is(bytes: &[u8], word: Word, name: &str) -> bool {
+    let name = name.as_bytes();
+    let mut i = word.start;
+    let mut j = 0;
+    while i < word.end && j < name.len() {
+        let (letter, length) = fold_at(bytes, i);
+        let (name_letter, name_length) = fold_at(name, j);
+        if letter != name_letter {
+            return false;
+        }
+        i += length;
+        j += name_length;
+    }
+    i == word.end && j == name.len()
+}
+
+const fn parse_month(bytes: &[u8], word: Word) -> Option<i32> {
+    let mut month = 1;
+    while month <= 13 {
+        if word_is(bytes, word, FRENCH_MONTH_NAME[month]) {
+            return Some(month as i32);
+        }
+        month += 1;
+    }
+    let mut i = 0;
+    while i < COMPLEMENTARY_NAME.len() {
+        if word_is(bytes, word, COMPLEMENTARY_NAME[i]) {
+            return Some(13);
+        }
+        i += 1;
+    }
+    None
+}
+
+pub const fn parse_french(text: &str) -> Result<FrenchDate, LiteralError> {
+    let bytes = text.as_bytes();
+    let word = next_word(bytes, 0);
+    let day = match parse_number(bytes, word) {
+        Some(day) => day,
+        None => return Err(LiteralError::InvalidDay),
+    };
+    let word = next_word(bytes, word.end);
+    let month = match parse_month(bytes, word) {
+        Some(month) => month,
+        None => return Err(LiteralError::UnknownMonth),
+    };
+    let mut word = next_word(bytes, word.end);
+    let an = word_is(bytes, word, "an");
+    if an {
+        word = next_word(bytes, word.end);
+    }
+    if word.start == word.end {
+        return Err(LiteralError::MissingYear);
+    }
+    let year = match parse_number(bytes, word) {
+        Some(year) => year,
+        None => match roman::parse_year(bytes.split_at(word.end).0.split_at(word.start).1, !an) {
+            Some(year) => year,
+            None => return Err(LiteralError::InvalidYear),
+        },
+    };
+    if next_word(bytes, word.end).start != bytes.len() {
+        return Err(LiteralError::UnexpectedWord);
+    }
+    match FrenchDate::new(year, month, day) {
+        Ok(french_date) => Ok(french_date),
+        Err(error) => Err(LiteralError::Date(error)),
+    }
+}
+
+/* The number of at least min_digits digits at the position, and the
+ * position after it.  A number too large for an i32 gives i32::MAX, so
+ * that a well written date with a huge year is out of range rather than
+ * malformed. */
+const fn parse_digits(bytes: &[u8], start: usize, min_digits: usize) -> Option<(i32, usize)> {
+    let mut end = start;
+    while end < bytes.len() && bytes[end].is_ascii_digit() {
+        end += 1;
+    }
+    if end - start < min_digits {
+        return None;
+    }
+    match parse_number(bytes, Word {start, end}) {
+        Some(number) => Some((number, end)),
+        None => Some((i32::MAX, end)),
+    }
+}
+
+/* Parse "YYYY-MM-DD" to the year, counting years B.C. and A.D., the month
+ * and the day, or None if the text is malformed.  The date is not
+ * checked. */
+pub(crate) const fn parse_iso(text: &str) -> Option<(i32, i32, i32)> {
+    let bytes = text.as_bytes();
+    let (negative, mut position) = match bytes.first() {
+        Some(b'-') => (true, 1),
+        Some(b'+') => (false, 1),
+        _ => (false, 0),
+    };
+    let mut fields = [0; 3];
+    let mut i = 0;
+    while i < 3 {
+        if i > 0 {
+            if position >= bytes.len() || bytes[position] != b'-' {
+                return None;
+            }
+            position += 1;
+        }
+        match parse_digits(bytes, position, if i == 0 {4} else {2}) {
+            Some((number, end)) => {
+                fields[i] = number;
+                position = end;
+            }
+            None => return None,
+        }
+        i += 1;
+    }
+    if position != bytes.len() {
+        return None;
+    }
+    let year = if negative {-fields[0]} else {fields[0]};
+    Some((if year <= 0 {year - 1} else {year}, fields[1], fields[2]))
+}
+
+pub const fn parse_gregorian(text: &str) -> Result<GregorianDate, LiteralError> {
+    let (year, month, day) = match parse_iso(text) {
+        Some(fields) => fields,
+        None => return Err(LiteralError::Malformed),
+    };
+    match GregorianDate::new(year, month, day) {
+        Ok(gregorian_date) => Ok(gregorian_date),
+        Err(error) => Err(LiteralError::Date(error)),
+    }
+}
+
+/* A malformed or invalid literal must fail the build, which only the
+ * doctests can check. */
+#[cfg_attr(doctest, doc = r#"
+```compile_fail,E0080
+let _ = french_republican_calendar::french_date!("1 vendemiaire mix");
+```
+```compile_fail,E0080
+let _ = french_republican_calendar::french_date!("31 brumaire VIII");
+```
+```compile_fail,E0080
+let _ = french_republican_calendar::french_date!("6 Extra II");
+```
+```
+let _ = french_republican_calendar::french_date!("6 Extra III");
+```
+"#)]
+#[macro_export]
+macro_rules! french_date {
+    ($text:expr) => {{
+        const DATE: $crate::french_calendar::FrenchDate = match $crate::literal::parse_french($text) {
+            ::core::result::Result::Ok(date) => date,
+            ::core::result::Result::Err(error) => ::core::panic!("{}", error.message()),
+        };
+        DATE
+    }};
+}
+
+#[cfg_attr(doctest, doc = r#"
+```compile_fail,E0080
+let _ = french_republican_calendar::gregorian_date!("1799-11-31");
+```
+```compile_fail,E0080
+let _ = french_republican_calendar::gregorian_date!("9 November 1799");
+```
+```
+let _ = french_republican_calendar::gregorian_date!("1799-11-09");
+```
+"#)]
+#[macro_export]
+macro_rules! gregorian_date {
+    ($text:expr) => {{
+        const DATE: $crate::gregorian_calendar::GregorianDate = match $crate::literal::parse_gregorian($text) {
+            ::core::result::Result::Ok(date) => date,
+            ::core::result::Result::Err(error) => ::core::panic!("{}", error.message()),
+        };
+        DATE
+    }};
+}
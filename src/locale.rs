@@ -0,0 +1,373 @@
+/**************************************************************************
+ *
+ * These are the externally visible components of this file:
+ *
+ *     pub enum Locale
+ *
+ * The languages in which the names of the months and days are given:
+ *
+ *     French            the names of the decree of 1793 and the French
+ *                       names of the Gregorian months and days of the week.
+ *     English           the usual English translations of the French
+ *                       republican names (Vintage, Mist, Frost...; First
+ *                       day, Second day...; Grape, Saffron, Chestnut...)
+ *                       and the English Gregorian names.
+ *     BritishSatirical  the names invented by British wits in the 1790s to
+ *                       mock the French republican months (Wheezy, Sneezy,
+ *                       Freezy...).
+ *
+ * A name that is not translated in a locale is taken from its fallback
+ * locale, BritishSatirical falling back to English and English to French.
+ * So the satirical locale only renames the French republican months.
+ *
+ *     pub fn Locale::fallback(self) -> Option<Locale>
+ *
+ * Return the locale in which the names missing from this one are looked
+ * up, or None for French, which has all the names.
+ *
+ *     pub fn Locale::republican_month_name(self, month: i32) -> &'static str
+ *     pub fn Locale::decade_day_name(self, day: i32) -> &'static str
+ *     pub fn Locale::short_decade_day_name(self, day: i32) -> &'static str
+ *     pub fn Locale::sansculottide_name(self, day: i32) -> &'static str
+ *     pub fn Locale::rural_day_name(self, day_of_year: i32) -> &'static str
+ *
+ * Convert a French republican month number (1 to 13), a day of the
+ * décade (1 to 10), a complementary day (1 to 6) or a day of the year (1
+ * to 366) to its name, as the tables of french_calendar.
+ *
+ *     pub fn Locale::gregorian_month_name(self, month: i32) -> &'static str
+ *     pub fn Locale::short_gregorian_month_name(self, month: i32) -> &'static str
+ *     pub fn Locale::day_of_week_name(self, day_of_week: i32) -> &'static str
+ *     pub fn Locale::short_day_of_week_name(self, day_of_week: i32) -> &'static str
+ *
+ * Convert a Gregorian month number (1 to 12) or a day-of-week number (0
+ * for Sunday to 6), as returned from day_of_week(), to its name, as the
+ * tables of gregorian_calendar.
+ *
+ * All these routines return a zero length string for a number out of
+ * range.
+ *
+ **************************************************************************/
+
+use crate::french_calendar::{
+    DECADE_DAY_NAME, FRENCH_MONTH_NAME, RURAL_DAY_NAME, SANSCULOTTIDE_NAME, SHORT_DECADE_DAY_NAME,
+};
+use crate::gregorian_calendar::{LONG_DAY_NAME, LONG_MONTH_NAME, SHORT_DAY_NAME, SHORT_MONTH_NAME};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    #[default]
+    French,
+    English,
+    BritishSatirical,
+}
+
+const ENGLISH_MONTH_NAME: &[&str] = &[
+    "",
+    "Vintage",
+    "Mist",
+    "Frost",
+    "Snow",
+    "Rain",
+    "Wind",
+    "Seed",
+    "Blossom",
+    "Meadow",
+    "Harvest",
+    "Heat",
+    "Fruit",
+    "Complementary Days"
+];
+
+const SATIRICAL_MONTH_NAME: &[&str] = &[
+    "",
+    "Wheezy",
+    "Sneezy",
+    "Freezy",
+    "Slippy",
+    "Drippy",
+    "Nippy",
+    "Showery",
+    "Flowery",
+    "Bowery",
+    "Hoppy",
+    "Croppy",
+    "Poppy"
+];
+
+const ENGLISH_SANSCULOTTIDE_NAME: &[&str] = &[
+    "",
+    "Virtue",
+    "Genius",
+    "Labour",
+    "Opinion",
+    "Rewards",
+    "Revolution"
+];
+
+const ENGLISH_DECADE_DAY_NAME: &[&str] = &[
+    "",
+    "First day",
+    "Second day",
+    "Third day",
+    "Fourth day",
+    "Fifth day",
+    "Sixth day",
+    "Seventh day",
+    "Eighth day",
+    "Ninth day",
+    "Tenth day"
+];
+
+const SHORT_ENGLISH_DECADE_DAY_NAME: &[&str] = &[
+    "",
+    "1st",
+    "2nd",
+    "3rd",
+    "4th",
+    "5th",
+    "6th",
+    "7th",
+    "8th",
+    "9th",
+    "10th"
+];
+
+const ENGLISH_RURAL_DAY_NAME: [&str; 367] = [
+    "",
+    /* Vintage */
+    "Grape", "Saffron", "Chestnut", "Colchicum", "Horse",
+    "Impatiens", "Carrot", "Amaranth", "Parsnip", "Vat",
+    "Potato", "Everlasting", "Squash", "Mignonette", "Donkey",
+    "Four o'clock flower", "Pumpkin", "Buckwheat", "Sunflower", "Wine press",
+    "Hemp", "Peach", "Turnip", "Amaryllis", "Ox",
+    "Aubergine", "Chili pepper", "Tomato", "Barley", "Barrel",
+    /* Mist */
+    "Apple", "Celery", "Pear", "Beetroot", "Goose",
+    "Heliotrope", "Fig", "Black salsify", "Whitebeam", "Plough",
+    "Salsify", "Water chestnut", "Jerusalem artichoke", "Endive", "Turkey",
+    "Skirret", "Watercress", "Leadwort", "Pomegranate", "Harrow",
+    "Baccharis", "Azarole", "Madder", "Orange", "Pheasant",
+    "Pistachio", "Tuberous pea", "Quince", "Service tree", "Roller",
+    /* Frost */
+    "Rampion", "Fodder turnip", "Chicory", "Medlar", "Pig",
+    "Corn salad", "Cauliflower", "Honey", "Juniper", "Pickaxe",
+    "Wax", "Horseradish", "Cedar", "Fir", "Roe deer",
+    "Gorse", "Cypress", "Ivy", "Savin juniper", "Grubbing hoe",
+    "Sugar maple", "Heather", "Reed", "Sorrel", "Cricket",
+    "Pine nut", "Cork", "Truffle", "Olive", "Shovel",
+    /* Snow */
+    "Peat", "Coal", "Bitumen", "Sulphur", "Dog",
+    "Lava", "Topsoil", "Manure", "Saltpetre", "Flail",
+    "Granite", "Clay", "Slate", "Sandstone", "Rabbit",
+    "Flint", "Marl", "Limestone", "Marble", "Winnowing basket",
+    "Gypsum", "Salt", "Iron", "Copper", "Cat",
+    "Tin", "Lead", "Zinc", "Mercury", "Sieve",
+    /* Rain */
+    "Spurge laurel", "Moss", "Butcher's broom", "Snowdrop", "Bull",
+    "Laurustinus", "Tinder polypore", "Mezereon", "Poplar", "Axe",
+    "Hellebore", "Broccoli", "Laurel", "Filbert", "Cow",
+    "Box", "Lichen", "Yew", "Lungwort", "Billhook",
+    "Pennycress", "Daphne", "Couch grass", "Knotgrass", "Hare",
+    "Woad", "Hazel", "Cyclamen", "Celandine", "Sleigh",
+    /* Wind */
+    "Coltsfoot", "Dogwood", "Stock", "Privet", "Billy goat",
+    "Wild ginger", "Italian buckthorn", "Violet", "Goat willow", "Spade",
+    "Daffodil", "Elm", "Fumitory", "Hedge mustard", "Nanny goat",
+    "Spinach", "Leopard's bane", "Pimpernel", "Chervil", "Line",
+    "Mandrake", "Parsley", "Scurvy-grass", "Daisy", "Tuna",
+    "Dandelion", "Wood anemone", "Maidenhair fern", "Ash", "Dibber",
+    /* Seed */
+    "Primrose", "Plane tree", "Asparagus", "Tulip", "Hen",
+    "Chard", "Birch", "Jonquil", "Alder", "Hatchery",
+    "Periwinkle", "Hornbeam", "Morel", "Beech", "Bee",
+    "Lettuce", "Larch", "Hemlock", "Radish", "Hive",
+    "Judas tree", "Romaine lettuce", "Horse chestnut", "Rocket", "Pigeon",
+    "Lilac", "Anemone", "Pansy", "Bilberry", "Grafting knife",
+    /* Blossom */
+    "Rose", "Oak", "Fern", "Hawthorn", "Nightingale",
+    "Columbine", "Lily of the valley", "Mushroom", "Hyacinth", "Rake",
+    "Rhubarb", "Sainfoin", "Wallflower", "Fan palm", "Silkworm",
+    "Comfrey", "Burnet", "Basket of gold", "Orache", "Hoe",
+    "Sea lavender", "Fritillary", "Borage", "Valerian", "Carp",
+    "Spindle tree", "Chive", "Bugloss", "Wild mustard", "Shepherd's crook",
+    /* Meadow */
+    "Alfalfa", "Daylily", "Clover", "Angelica", "Duck",
+    "Lemon balm", "Oat grass", "Martagon lily", "Wild thyme", "Scythe",
+    "Strawberry", "Betony", "Pea", "Acacia", "Quail",
+    "Carnation", "Elder", "Poppy", "Lime tree", "Pitchfork",
+    "Cornflower", "Chamomile", "Honeysuckle", "Bedstraw", "Tench",
+    "Jasmine", "Vervain", "Thyme", "Peony", "Cart",
+    /* Harvest */
+    "Rye", "Oat", "Onion", "Speedwell", "Mule",
+    "Rosemary", "Cucumber", "Shallot", "Wormwood", "Sickle",
+    "Coriander", "Artichoke", "Clove", "Lavender", "Chamois",
+    "Tobacco", "Redcurrant", "Vetchling", "Cherry", "Sheep pen",
+    "Mint", "Cumin", "Bean", "Alkanet", "Guinea fowl",
+    "Sage", "Garlic", "Vetch", "Wheat", "Shawm",
+    /* Heat */
+    "Spelt", "Mullein", "Melon", "Ryegrass", "Ram",
+    "Horsetail", "Mugwort", "Safflower", "Blackberry", "Watering can",
+    "Panic grass", "Glasswort", "Apricot", "Basil", "Ewe",
+    "Marshmallow", "Flax", "Almond", "Gentian", "Lock",
+    "Carline thistle", "Caper", "Lentil", "Elecampane", "Otter",
+    "Myrtle", "Rapeseed", "Lupin", "Cotton", "Mill",
+    /* Fruit */
+    "Plum", "Millet", "Puffball", "Winter barley", "Salmon",
+    "Tuberose", "Sugar barley", "Dogbane", "Liquorice", "Ladder",
+    "Watermelon", "Fennel", "Barberry", "Walnut", "Trout",
+    "Lemon", "Teasel", "Buckthorn", "Marigold", "Harvesting basket",
+    "Wild rose", "Hazelnut", "Hops", "Sorghum", "Crayfish",
+    "Bitter orange", "Goldenrod", "Maize", "Sweet chestnut", "Basket",
+    /* Complementary Days */
+    "Celebration of Virtue", "Celebration of Genius", "Celebration of Labour", "Celebration of Opinion", "Celebration of Rewards",
+    "Celebration of the Revolution"
+];
+
+const FRENCH_GREGORIAN_MONTH_NAME: &[&str] = &[
+    "",
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre"
+];
+
+const SHORT_FRENCH_GREGORIAN_MONTH_NAME: &[&str] = &[
+    "",
+    "janv.",
+    "févr.",
+    "mars",
+    "avr.",
+    "mai",
+    "juin",
+    "juil.",
+    "août",
+    "sept.",
+    "oct.",
+    "nov.",
+    "déc."
+];
+
+const FRENCH_DAY_NAME: &[&str] = &[
+    "dimanche",
+    "lundi",
+    "mardi",
+    "mercredi",
+    "jeudi",
+    "vendredi",
+    "samedi"
+];
+
+const SHORT_FRENCH_DAY_NAME: &[&str] = &[
+    "dim.",
+    "lun.",
+    "mar.",
+    "mer.",
+    "jeu.",
+    "ven.",
+    "sam."
+];
+
+impl Locale {
+    pub fn fallback(self) -> Option<Locale> {
+        match self {
+            Locale::French => None,
+            Locale::English => Some(Locale::French),
+            Locale::BritishSatirical => Some(Locale::English),
+        }
+    }
+
+    /* Look up a name in the table of the locale, then in the tables of its
+     * fallbacks. */
+    fn name(self, table: fn(Locale) -> &'static [&'static str], index: i32) -> &'static str {
+        let mut locale = Some(self);
+        while let Some(current) = locale {
+            let name = usize::try_from(index).ok().and_then(|index| table(current).get(index));
+            match name {
+                Some(name) if !name.is_empty() => return name,
+                _ => locale = current.fallback(),
+            }
+        }
+        ""
+    }
+
+    pub fn republican_month_name(self, month: i32) -> &'static str {
+        self.name(|locale| match locale {
+            Locale::French => FRENCH_MONTH_NAME,
+            Locale::English => ENGLISH_MONTH_NAME,
+            Locale::BritishSatirical => SATIRICAL_MONTH_NAME,
+        }, month)
+    }
+
+    pub fn decade_day_name(self, day: i32) -> &'static str {
+        self.name(|locale| match locale {
+            Locale::French => DECADE_DAY_NAME,
+            Locale::English => ENGLISH_DECADE_DAY_NAME,
+            Locale::BritishSatirical => &[],
+        }, day)
+    }
+
+    pub fn short_decade_day_name(self, day: i32) -> &'static str {
+        self.name(|locale| match locale {
+            Locale::French => SHORT_DECADE_DAY_NAME,
+            Locale::English => SHORT_ENGLISH_DECADE_DAY_NAME,
+            Locale::BritishSatirical => &[],
+        }, day)
+    }
+
+    pub fn sansculottide_name(self, day: i32) -> &'static str {
+        self.name(|locale| match locale {
+            Locale::French => SANSCULOTTIDE_NAME,
+            Locale::English => ENGLISH_SANSCULOTTIDE_NAME,
+            Locale::BritishSatirical => &[],
+        }, day)
+    }
+
+    pub fn rural_day_name(self, day_of_year: i32) -> &'static str {
+        self.name(|locale| match locale {
+            Locale::French => RURAL_DAY_NAME,
+            Locale::English => &ENGLISH_RURAL_DAY_NAME,
+            Locale::BritishSatirical => &[],
+        }, day_of_year)
+    }
+
+    pub fn gregorian_month_name(self, month: i32) -> &'static str {
+        self.name(|locale| match locale {
+            Locale::French => FRENCH_GREGORIAN_MONTH_NAME,
+            Locale::English => LONG_MONTH_NAME,
+            Locale::BritishSatirical => &[],
+        }, month)
+    }
+
+    pub fn short_gregorian_month_name(self, month: i32) -> &'static str {
+        self.name(|locale| match locale {
+            Locale::French => SHORT_FRENCH_GREGORIAN_MONTH_NAME,
+            Locale::English => SHORT_MONTH_NAME,
+            Locale::BritishSatirical => &[],
+        }, month)
+    }
+
+    pub fn day_of_week_name(self, day_of_week: i32) -> &'static str {
+        self.name(|locale| match locale {
+            Locale::French => FRENCH_DAY_NAME,
+            Locale::English => LONG_DAY_NAME,
+            Locale::BritishSatirical => &[],
+        }, day_of_week)
+    }
+
+    pub fn short_day_of_week_name(self, day_of_week: i32) -> &'static str {
+        self.name(|locale| match locale {
+            Locale::French => SHORT_FRENCH_DAY_NAME,
+            Locale::English => SHORT_DAY_NAME,
+            Locale::BritishSatirical => &[],
+        }, day_of_week)
+    }
+}
@@ -2,7 +2,7 @@
 // use fltk::{app, enums::{FrameType, Shortcut}, frame::Frame, prelude::*, window::Window};
 use fltk::{
     app,
-    enums::{CallbackTrigger, Color, FrameType,},
+    enums::CallbackTrigger,
     frame::Frame,
     group::{Pack, PackType},
     input::IntInput,
@@ -23,21 +23,16 @@ enum Message {
 
 
 fn print_gregorian_date(frame: &mut Frame, fd: i32, fm: i32, fy: i32) -> bool {
-    let french_date = french_calendar::FrenchDate{day: fd, month: fm, year: fy};
-    let sdn = french_calendar::french_to_sdn(&french_date);
+    let sdn = match french_calendar::FrenchDate::new(fy, fm, fd) {
+        Ok(french_date) => french_calendar::french_to_sdn(&french_date),
+        Err(_) => 0,
+    };
     let gregorian_date = gregorian_calendar::sdn_to_gregorian(sdn);
-    if gregorian_date.day == 0 {
+    if gregorian_date.day() == 0 {
         frame.set_label("");
         false
     } else {
-        frame.set_label(
-            format!(
-                "{} {} {:4}",
-                gregorian_date.day,
-                gregorian_calendar::LONG_MONTH_NAME[gregorian_date.month as usize],
-                gregorian_date.year
-            ).as_str()
-        );
+        frame.set_label(gregorian_date.format("%-d %B %_4Y").to_string().as_str());
         true
     }
 }
@@ -75,9 +70,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     hpack.set_type(PackType::Horizontal);
     hpack.set_spacing(10);
 
-    let mut hpack2 = Pack::new(90, 130, 200, 25, "Gregorian");
+    let hpack2 = Pack::new(90, 130, 200, 25, "Gregorian");
 	let mut frame = Frame::default().with_size(0, 25);
-    hpack.end();
+    hpack2.end();
 
     win.make_resizable(false);
     win.end();
@@ -0,0 +1,304 @@
+/**************************************************************************
+ *
+ * This file is only compiled with the "alloc" feature.  These are the
+ * externally visible components of this file:
+ *
+ *     pub fn parse_republican(text: &str) -> Result<FrenchDate, ParseError>
+ *     pub fn parse_republican_with_rule(text: &str, rule: LeapRule) -> Result<FrenchDate, ParseError>
+ *
+ * Parse a French republican date written in words, as it is found in the
+ * documents of the time, under the default leap year rule or under the
+ * given rule.  FrenchDate also implements FromStr with parse_republican(),
+ * so that a date can be parsed with "18 brumaire an VIII".parse().  These
+ * forms are accepted:
+ *
+ *     18 brumaire an VIII
+ *     le 9 thermidor de l'an 2
+ *     1er vendémiaire an III
+ *     5e jour complémentaire an XI
+ *     premier jour des sansculottides de l'an IV
+ *     jour de la Révolution an III
+ *     Octidi 18 Brumaire an VIII
+ *
+ * The case, the accents and the punctuation are ignored.  The words are:
+ *
+ *     - an optional "le";
+ *     - an optional name of the day of the décade (Primidi to Décadi),
+ *       which is not checked against the date, so that the dates
+ *       displayed by FrenchDate are read back, e.g. "Octidi 18 Brumaire
+ *       an VIII";
+ *     - the day, in Arabic numerals, possibly as an ordinal (1er, 1re, 2e,
+ *       2ème...), or "premier";
+ *     - the month, whose name can be abbreviated to its first letters if
+ *       there is no ambiguity (vend, brum, therm...), or for the
+ *       complementary days "jour(s) complémentaire(s)", "sansculottide(s)"
+ *       or "sans-culottide(s)", possibly preceded by "jour(s) des";
+ *     - or instead of the day and month, the name of a complementary day,
+ *       possibly preceded by "jour de la", "fête de la", "fête du"...;
+ *     - an optional "de l'an" or "an";
+ *     - the year, in Roman numerals as written by roman::to_roman(), IIII
+ *       being also accepted for 4, or in Arabic numerals.  Without "an",
+ *       the Roman numerals are only read from I to XCIX, as many words
+ *       could be taken for a larger year, e.g. "dix" for 509.
+ *
+ *     pub struct ParseError
+ *     pub enum ParseErrorKind
+ *
+ * The error returned when a date cannot be parsed.  ParseError::kind()
+ * tells why:
+ *
+ *     Empty             there is nothing to parse.
+ *     InvalidDay        the day is not a number or an ordinal.
+ *     UnknownMonth      the month is not a known name or abbreviation.
+ *     MissingYear       the year is missing.
+ *     InvalidYear       the year is neither Roman nor Arabic numerals.
+ *     UnexpectedToken   there is an extra word after the year.
+ *     Date(error)       the words are correct but the date does not exist,
+ *                       e.g. the 31 brumaire.
+ *
+ * and ParseError::span() gives the position of the failing word in the
+ * text, as a range of bytes.
+ *
+ **************************************************************************/
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+use core::str::FromStr;
+
+use crate::error::CalendarError;
+use crate::french_calendar::{fold, FrenchDate, LeapRule, RepublicanMonth, Sansculottide, DECADE_DAY_NAME};
+use crate::roman;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseErrorKind {
+    Empty,
+    InvalidDay,
+    UnknownMonth,
+    MissingYear,
+    InvalidYear,
+    UnexpectedToken,
+    Date(CalendarError),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    span: Range<usize>,
+}
+
+impl ParseError {
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::Empty => f.write_str("empty date"),
+            ParseErrorKind::InvalidDay => write!(f, "invalid day at {:?}", self.span),
+            ParseErrorKind::UnknownMonth => write!(f, "unknown month at {:?}", self.span),
+            ParseErrorKind::MissingYear => write!(f, "missing year at {}", self.span.start),
+            ParseErrorKind::InvalidYear => write!(f, "invalid year at {:?}", self.span),
+            ParseErrorKind::UnexpectedToken => write!(f, "unexpected word at {:?}", self.span),
+            ParseErrorKind::Date(error) => write!(f, "{} at {:?}", error, self.span),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/* A word of the text, folded to lower case and without accents. */
+struct Token {
+    word: String,
+    span: Range<usize>,
+}
+
+fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices().chain(core::iter::once((text.len(), ' '))) {
+        match (c.is_alphanumeric(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                tokens.push(Token {word: fold(&text[s..i]).collect(), span: s..i});
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    tokens
+}
+
+/* Parse a day of the month: 18, 1er, 2e, 3ème, premier... */
+fn parse_day(word: &str) -> Option<i32> {
+    if word == "premier" || word == "premiere" {
+        return Some(1);
+    }
+    let digits = word.len() - word.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    if digits == 0 || digits > 2 {
+        return None;
+    }
+    match &word[digits..] {
+        "" | "e" | "eme" | "ieme" | "er" | "re" => word[..digits].parse().ok(),
+        _ => None,
+    }
+}
+
+fn parse_month(word: &str) -> Option<RepublicanMonth> {
+    let mut matches = RepublicanMonth::ALL.iter()
+        .filter(|month| word.len() >= 3 && fold(month.name()).collect::<String>().starts_with(word));
+    match (matches.next(), matches.next()) {
+        (Some(&month), None) => Some(month),
+        _ => None,
+    }
+}
+
+fn parse_sansculottide(word: &str) -> Option<Sansculottide> {
+    Sansculottide::ALL.iter()
+        .find(|sansculottide| {
+            let name: String = fold(sansculottide.name()).collect();
+            name == word || name.strip_suffix('s') == Some(word)
+        })
+        .copied()
+}
+
+fn is_decade_day(word: &str) -> bool {
+    DECADE_DAY_NAME.iter().skip(1).any(|name| fold(name).eq(word.chars()))
+}
+
+fn parse_year(word: &str, bare: bool) -> Option<i32> {
+    if word.chars().all(|c| c.is_ascii_digit()) {
+        word.parse().ok()
+    } else {
+        roman::parse_year(word.as_bytes(), bare)
+    }
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    position: usize,
+    end: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&str> {
+        self.tokens.get(self.position).map(|token| token.word.as_str())
+    }
+
+    /* Skip the next word if it is one of the given words. */
+    fn skip(&mut self, words: &[&str]) -> bool {
+        let found = self.peek().is_some_and(|word| words.contains(&word));
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn span(&self) -> Range<usize> {
+        self.tokens.get(self.position).map_or(self.end..self.end, |token| token.span.clone())
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError {kind, span: self.span()}
+    }
+
+    /* Skip the words naming the complementary days, if they are next. */
+    fn skip_sansculottides(&mut self) -> bool {
+        let start = self.position;
+        let jour = self.skip(&["jour", "jours"]);
+        if jour {
+            self.skip(&["des"]);
+        }
+        if self.skip(&["complementaire", "complementaires", "sansculottide", "sansculottides"])
+            || (self.skip(&["sans"]) && self.skip(&["culottide", "culottides"])) {
+            return true;
+        }
+        self.position = start;
+        false
+    }
+
+    /* Parse the day and month, returning the month number, the day and
+     * the span of the day. */
+    fn parse_day_and_month(&mut self) -> Result<(i32, i32, Range<usize>), ParseError> {
+        let start = self.position;
+        if self.skip(&["jour", "fete"]) {
+            self.skip(&["de", "du", "des"]);
+            self.skip(&["la", "l"]);
+        }
+        let span = self.span();
+        if let Some(sansculottide) = self.peek().and_then(parse_sansculottide) {
+            self.position += 1;
+            return Ok((13, sansculottide.number(), span));
+        }
+        self.position = start;
+
+        let span = self.span();
+        let day = self.peek().and_then(parse_day).ok_or(self.error(ParseErrorKind::InvalidDay))?;
+        self.position += 1;
+
+        if self.skip_sansculottides() {
+            return Ok((13, day, span));
+        }
+        let month = self.peek().and_then(parse_month).ok_or(self.error(ParseErrorKind::UnknownMonth))?;
+        self.position += 1;
+        Ok((month.number(), day, span))
+    }
+
+    fn parse_year(&mut self) -> Result<(i32, Range<usize>), ParseError> {
+        if self.skip(&["de", "du"]) {
+            self.skip(&["l"]);
+        }
+        let an = self.skip(&["an"]);
+
+        let span = self.span();
+        let word = self.peek().ok_or(self.error(ParseErrorKind::MissingYear))?;
+        let year = parse_year(word, !an).ok_or(self.error(ParseErrorKind::InvalidYear))?;
+        self.position += 1;
+        Ok((year, span))
+    }
+}
+
+pub fn parse_republican(text: &str) -> Result<FrenchDate, ParseError> {
+    parse_republican_with_rule(text, LeapRule::default())
+}
+
+pub fn parse_republican_with_rule(text: &str, rule: LeapRule) -> Result<FrenchDate, ParseError> {
+    let tokens = tokenize(text);
+    let mut parser = Parser {tokens: &tokens, position: 0, end: text.len()};
+    if tokens.is_empty() {
+        return Err(parser.error(ParseErrorKind::Empty));
+    }
+
+    parser.skip(&["le"]);
+    if parser.peek().is_some_and(is_decade_day) {
+        parser.position += 1;
+    }
+    let (month, day, day_span) = parser.parse_day_and_month()?;
+    let (year, year_span) = parser.parse_year()?;
+    if parser.peek().is_some() {
+        return Err(parser.error(ParseErrorKind::UnexpectedToken));
+    }
+
+    FrenchDate::new_with_rule(year, month, day, rule).map_err(|error| {
+        let span = match error {
+            CalendarError::OutOfRange | CalendarError::YearZero => year_span,
+            _ => day_span,
+        };
+        ParseError {kind: ParseErrorKind::Date(error), span}
+    })
+}
+
+impl FromStr for FrenchDate {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<FrenchDate, ParseError> {
+        parse_republican(text)
+    }
+}
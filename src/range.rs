@@ -0,0 +1,262 @@
+/**************************************************************************
+ *
+ * These are the externally visible components of this file:
+ *
+ *     pub struct DateRange<T>
+ *
+ * An iterator over the dates of any calendar from a start date to an end
+ * date, in chronological order.  It is double ended, so that .rev() walks
+ * the dates backwards, and it knows its length.
+ *
+ *     pub fn DateRange::inclusive(start: T, end: T) -> DateRange<T>
+ *     pub fn DateRange::exclusive(start: T, end: T) -> DateRange<T>
+ *
+ * Create a range of every day from start to end, end being included or
+ * not.  The range is empty if end is before start, or if one of the dates
+ * has no SDN (see CalendarDate::try_days_until()).
+ *
+ *     pub fn DateRange::by(self, step: Step) -> DateRange<T>
+ *
+ * Change the step of the range, which is one day by default.
+ *
+ *     pub enum Step
+ *
+ * The step of a range: Days(n), Decades(n) (ten days), Months(n) or
+ * Years(n), with n > 0.  The dates of a range are computed from the start
+ * date, so that a range from January 31 by months gives the last day of
+ * the shorter months (see CalendarDate::add_months() with the Clamp
+ * policy), then March 31.
+ *
+ *     pub struct Interval<T>
+ *
+ * A period of time between two dates of any calendar, both included.
+ *
+ *     pub fn Interval::new(start: T, end: T) -> Option<Interval<T>>
+ *
+ * Create an interval, or None if end is before start.
+ *
+ *     pub fn Interval::year(year: i32) -> Result<Interval<T>, CalendarError>
+ *     pub fn Interval::month(year: i32, month: i32) -> Result<Interval<T>, CalendarError>
+ *
+ * Create the interval of all the days of a year, or of a month (month 13
+ * being the complementary days of the French republican calendar).
+ *
+ *     pub fn Interval::start(&self) -> T
+ *     pub fn Interval::end(&self) -> T
+ *     pub fn Interval::length(&self) -> Days
+ *     pub fn Interval::contains(&self, date: &T) -> bool
+ *     pub fn Interval::overlaps(&self, other: &Interval<T>) -> bool
+ *     pub fn Interval::intersection(&self, other: &Interval<T>) -> Option<Interval<T>>
+ *     pub fn Interval::days(&self) -> DateRange<T>
+ *
+ * Read the bounds, the number of days, tell whether a date is in the
+ * interval or whether two intervals have a day in common, compute the
+ * common days of two intervals and walk the days.  For example, every day
+ * of the year VIII and the décades of Germinal of the year II are:
+ *
+ *    Interval::<FrenchDate>::year(8)?.days()
+ *    Interval::<FrenchDate>::month(2, 7)?.days().by(Step::Decades(1))
+ *
+ **************************************************************************/
+
+use core::iter::FusedIterator;
+
+use crate::calendar::{astronomical_year, month_count, CalendarDate, ClampPolicy, Days};
+use crate::error::CalendarError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Step {
+    Days(i32),
+    Decades(i32),
+    Months(i32),
+    Years(i32),
+}
+
+#[derive(Debug, Clone)]
+pub struct DateRange<T> {
+    start: T,
+    end: T,
+    inclusive: bool,
+    step: Step,
+    front: usize,
+    back: usize,
+}
+
+impl<T: CalendarDate + Copy + Ord> DateRange<T> {
+    pub fn inclusive(start: T, end: T) -> DateRange<T> {
+        DateRange::new(start, end, true)
+    }
+
+    pub fn exclusive(start: T, end: T) -> DateRange<T> {
+        DateRange::new(start, end, false)
+    }
+
+    fn new(start: T, end: T, inclusive: bool) -> DateRange<T> {
+        let range = DateRange {start, end, inclusive, step: Step::Days(1), front: 0, back: 0};
+        range.by(Step::Days(1))
+    }
+
+    pub fn by(self, step: Step) -> DateRange<T> {
+        let (Step::Days(n) | Step::Decades(n) | Step::Months(n) | Step::Years(n)) = step;
+        assert!(n > 0, "the step of a date range must be positive");
+
+        let mut range = DateRange {step, front: 0, back: 0, ..self};
+        range.back = match step {
+            Step::Days(_) | Step::Decades(_) => {
+                let step_days = range.step_days();
+                match range.start.try_days_until(&range.end) {
+                    Ok(Days(days)) => {
+                        let days = if range.inclusive {days as i64 + 1} else {days as i64};
+                        if days > 0 {((days - 1) / step_days + 1) as usize} else {0}
+                    }
+                    Err(_) => 0,
+                }
+            }
+            Step::Months(n) => {
+                let start = month_count::<T>(range.start.year(), range.start.month());
+                let end = month_count::<T>(range.end.year(), range.end.month());
+                match (start, end) {
+                    (Ok(start), Ok(end)) => range.count_steps(end - start, n),
+                    _ => 0,
+                }
+            }
+            Step::Years(n) => {
+                let years = astronomical_year(range.end.year()) - astronomical_year(range.start.year());
+                range.count_steps(years, n)
+            }
+        };
+        range
+    }
+
+    /* The number of dates of a range by months or years, given the number of
+     * months or years from the start to the end.  The dates before the
+     * month or the year of the end are in the range, and the last one
+     * depends on its day. */
+    fn count_steps(&self, to_end: i64, n: i32) -> usize {
+        if to_end < 0 {
+            return 0;
+        }
+        let last = (to_end / n as i64) as usize;
+        if self.get(last).is_some() {last + 1} else {last}
+    }
+
+    fn step_days(&self) -> i64 {
+        match self.step {
+            Step::Days(n) => n as i64,
+            Step::Decades(n) => n as i64 * 10,
+            Step::Months(_) | Step::Years(_) => 0,
+        }
+    }
+
+    /* The i-th date of the range, or None if it is after the end. */
+    fn get(&self, i: usize) -> Option<T> {
+        let date = match self.step {
+            Step::Days(_) | Step::Decades(_) => {
+                let days = i32::try_from(i as i64 * self.step_days()).ok()?;
+                self.start.add_days(Days(days))
+            }
+            Step::Months(n) => {
+                let months = i32::try_from(i as i64 * n as i64).ok()?;
+                self.start.add_months(months, ClampPolicy::Clamp)
+            }
+            Step::Years(n) => {
+                let years = i32::try_from(i as i64 * n as i64).ok()?;
+                self.start.add_years(years, ClampPolicy::Clamp)
+            }
+        };
+        date.ok().filter(|date| *date < self.end || (self.inclusive && *date == self.end))
+    }
+}
+
+impl<T: CalendarDate + Copy + Ord> Iterator for DateRange<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.front += 1;
+        self.get(self.front - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let length = self.back.saturating_sub(self.front);
+        (length, Some(length))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+impl<T: CalendarDate + Copy + Ord> DoubleEndedIterator for DateRange<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.get(self.back)
+    }
+}
+
+impl<T: CalendarDate + Copy + Ord> ExactSizeIterator for DateRange<T> {}
+
+impl<T: CalendarDate + Copy + Ord> FusedIterator for DateRange<T> {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: CalendarDate + Copy + Ord> Interval<T> {
+    pub fn new(start: T, end: T) -> Option<Interval<T>> {
+        if end < start {
+            None
+        } else {
+            Some(Interval {start, end})
+        }
+    }
+
+    pub fn year(year: i32) -> Result<Interval<T>, CalendarError> {
+        let start = T::from_ymd(year, 1, 1)?;
+        let mut end = start.add_years(1, ClampPolicy::Strict)?;
+        end.prev_day();
+        Ok(Interval {start, end})
+    }
+
+    pub fn month(year: i32, month: i32) -> Result<Interval<T>, CalendarError> {
+        let start = T::from_ymd(year, month, 1)?;
+        let end = T::from_ymd(year, month, T::days_in_month(year, month))?;
+        Ok(Interval {start, end})
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    pub fn length(&self) -> Days {
+        Days(self.start.days_until(&self.end).0 + 1)
+    }
+
+    pub fn contains(&self, date: &T) -> bool {
+        self.start <= *date && *date <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    pub fn days(&self) -> DateRange<T> {
+        DateRange::inclusive(self.start, self.end)
+    }
+}
@@ -0,0 +1,268 @@
+/**************************************************************************
+ *
+ * These are the externally visible components of this file:
+ *
+ *     pub fn to_roman(number: i32) -> Option<String>
+ *
+ * Convert a number to Roman numerals in the usual subtractive notation,
+ * e.g. 1799 is MDCCXCIX.  None is returned for the numbers that cannot be
+ * written with the standard symbols, which are the ones outside of the
+ * range 1 to 3999.  It needs the "alloc" feature.
+ *
+ *     pub fn write_roman<W: fmt::Write>(out: &mut W, number: i32) -> fmt::Result
+ *
+ * Same as above, but the numerals are written to the output without
+ * allocating a string.  An error is returned for a number out of range.
+ *
+ *     pub fn parse_roman(roman: &str, mode: RomanMode) -> Result<i32, RomanError>
+ *
+ * Convert Roman numerals, in upper or lower case, to a number.  The mode
+ * tells which numerals are accepted:
+ *
+ *     Strict     only the numerals written by to_roman(), so that a number
+ *                has a single representation, from I to MMMCMXCIX.
+ *     Lenient    any sequence of symbols, a symbol being subtracted when a
+ *                larger one follows it and added otherwise, so that the
+ *                non-standard forms found in old documents, such as IIII,
+ *                IIX or MDCCCCX, are also accepted.  J is read as I, as in
+ *                iij for 3.  The number can be above 3999 (MMMM is 4000).
+ *
+ *     pub enum RomanError
+ *
+ * The reasons why parse_roman() can reject numerals:
+ *
+ *     Empty                 there is no numeral.
+ *     InvalidSymbol(pos)    the character at the given byte position is not
+ *                           a Roman numeral.
+ *     NotCanonical          the numerals are not written in the usual
+ *                           subtractive notation (strict mode).
+ *     OutOfRange            the number is zero, negative or too large.
+ *
+ *     pub fn from_roman(roman: &str) -> Option<i32>
+ *
+ * Same as parse_roman() in lenient mode, returning None on error.
+ *
+ * The years of the French republican calendar were written in Roman
+ * numerals, e.g. "an II".  They are used by the %Y directive of the
+ * formatting of French republican dates and by the parsing of dates
+ * written in words, which reads the years as the strict mode does, except
+ * that 4 may be written IIII as in many documents of the time, e.g. "an
+ * IIII" or "an XIIII".  A year that does not follow "an" is only read from
+ * I to XCIX, so that words such as "dix" are not taken for years.
+ *
+ **************************************************************************/
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt;
+
+const NUMERALS: [(i32, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RomanMode {
+    #[default]
+    Strict,
+    Lenient,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RomanError {
+    Empty,
+    InvalidSymbol(usize),
+    NotCanonical,
+    OutOfRange,
+}
+
+impl fmt::Display for RomanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RomanError::Empty => f.write_str("empty Roman numeral"),
+            RomanError::InvalidSymbol(position) => {
+                write!(f, "invalid Roman numeral symbol at {}", position)
+            }
+            RomanError::NotCanonical => f.write_str("non-standard Roman numeral"),
+            RomanError::OutOfRange => f.write_str("Roman numeral out of range"),
+        }
+    }
+}
+
+impl core::error::Error for RomanError {}
+
+pub fn write_roman<W: fmt::Write>(out: &mut W, number: i32) -> fmt::Result {
+    if !(1..=3999).contains(&number) {
+        return Err(fmt::Error);
+    }
+
+    let mut rest = number;
+    for &(value, numeral) in NUMERALS.iter() {
+        while rest >= value {
+            out.write_str(numeral)?;
+            rest -= value;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "alloc")]
+pub fn to_roman(number: i32) -> Option<String> {
+    let mut roman = String::new();
+    write_roman(&mut roman, number).ok()?;
+    Some(roman)
+}
+
+fn symbol_value(symbol: char, mode: RomanMode) -> Option<i32> {
+    match symbol.to_ascii_uppercase() {
+        'I' => Some(1),
+        'J' if mode == RomanMode::Lenient => Some(1),
+        'V' => Some(5),
+        'X' => Some(10),
+        'L' => Some(50),
+        'C' => Some(100),
+        'D' => Some(500),
+        'M' => Some(1000),
+        _ => None,
+    }
+}
+
+/* The symbols for one, five and ten of each decimal place, 0 meaning that
+ * there is none. */
+const PLACES: [(i32, [u8; 3]); 4] = [
+    (1000, [b'M', 0, 0]),
+    (100, *b"CDM"),
+    (10, *b"XLC"),
+    (1, *b"IVX"),
+];
+
+const TENS: [(i32, [u8; 3]); 2] = [PLACES[2], PLACES[3]];
+
+/* The numerals of the digits 0 to 9, as indexes in the symbols of their
+ * place. */
+const DIGITS: [&[usize]; 10] = [
+    &[],
+    &[0],
+    &[0, 0],
+    &[0, 0, 0],
+    &[0, 1],
+    &[1],
+    &[1, 0],
+    &[1, 0, 0],
+    &[1, 0, 0, 0],
+    &[0, 2],
+];
+
+/* Whether the numerals of a digit are at the position, ignoring the case. */
+const fn digit_matches(bytes: &[u8], position: usize, symbols: &[u8; 3], digit: &[usize]) -> bool {
+    if position + digit.len() > bytes.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < digit.len() {
+        let symbol = symbols[digit[i]];
+        if symbol == 0 || bytes[position + i].to_ascii_uppercase() != symbol {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/* The number written in the numerals of to_roman() with the given places,
+ * in upper or lower case, or None if they are not. */
+const fn parse_places(bytes: &[u8], places: &[(i32, [u8; 3])]) -> Option<i32> {
+    let mut number = 0;
+    let mut position = 0;
+    let mut place = 0;
+    while place < places.len() {
+        let (value, symbols) = places[place];
+        /* The longest numerals of a digit at the position, such as VIII
+         * rather than V or IX rather than I. */
+        let mut best = 0;
+        let mut digit = 1;
+        while digit < DIGITS.len() {
+            if DIGITS[digit].len() > DIGITS[best].len() && digit_matches(bytes, position, &symbols, DIGITS[digit]) {
+                best = digit;
+            }
+            digit += 1;
+        }
+        number += best as i32 * value;
+        position += DIGITS[best].len();
+        place += 1;
+    }
+    if position == bytes.len() && number > 0 {Some(number)} else {None}
+}
+
+pub(crate) const fn parse_canonical(bytes: &[u8]) -> Option<i32> {
+    parse_places(bytes, &PLACES)
+}
+
+/* A year in Roman numerals: the numerals of to_roman(), or the ones of a
+ * multiple of 10 followed by IIII.  A bare year, which does not follow
+ * "an", is only read below 100, since many words would be read as a year,
+ * e.g. "dix" as 509 or "mix" as 1009, and the years were written with two
+ * digits at most (the Paris Commune of 1871 revived the year LXXIX). */
+pub(crate) const fn parse_year(bytes: &[u8], bare: bool) -> Option<i32> {
+    let places: &[(i32, [u8; 3])] = if bare {&TENS} else {&PLACES};
+    let length = bytes.len();
+    if length >= 4 && digit_matches(bytes, length - 4, &PLACES[3].1, &[0, 0, 0, 0]) {
+        if length == 4 {
+            return Some(4);
+        }
+        return match parse_places(bytes.split_at(length - 4).0, places) {
+            Some(number) if number % 10 == 0 => Some(number + 4),
+            _ => None,
+        };
+    }
+    parse_places(bytes, places)
+}
+
+pub fn parse_roman(roman: &str, mode: RomanMode) -> Result<i32, RomanError> {
+    if let Some((i, _)) = roman.char_indices().find(|&(_, c)| symbol_value(c, mode).is_none()) {
+        return Err(RomanError::InvalidSymbol(i));
+    }
+    if roman.is_empty() {
+        return Err(RomanError::Empty);
+    }
+
+    /* A symbol is subtracted when a later symbol is larger. */
+    let mut number: i32 = 0;
+    let mut largest = 0;
+    for value in roman.chars().rev().filter_map(|c| symbol_value(c, mode)) {
+        if value < largest {
+            number = number.checked_sub(value).ok_or(RomanError::OutOfRange)?;
+        } else {
+            number = number.checked_add(value).ok_or(RomanError::OutOfRange)?;
+            largest = value;
+        }
+    }
+    if number <= 0 {
+        return Err(RomanError::OutOfRange);
+    }
+
+    if mode == RomanMode::Strict {
+        if number > 3999 {
+            return Err(RomanError::OutOfRange);
+        }
+        if parse_canonical(roman.as_bytes()).is_none() {
+            return Err(RomanError::NotCanonical);
+        }
+    }
+    Ok(number)
+}
+
+pub fn from_roman(roman: &str) -> Option<i32> {
+    parse_roman(roman, RomanMode::Lenient).ok()
+}
@@ -0,0 +1,151 @@
+/**************************************************************************
+ *
+ * These are the externally visible components of this file:
+ *
+ *     pub struct Sdn
+ *
+ * A SDN stored on 64 bits.  The CalendarDate trait and the try_ routines
+ * of each calendar take and return a Sdn.  The legacy routines of each
+ * calendar, which are thin wrappers of them, take and return the SDN as an
+ * i32, and zero for an invalid date, so they only handle the SDNs from 1
+ * (November 25, 4714 B.C. in the Gregorian calendar).  A Sdn can also be
+ * zero or negative, and it is always within the supported range:
+ *
+ *     pub const Sdn::MIN: Sdn
+ *     pub const Sdn::MAX: Sdn
+ *
+ * The first and last supported SDNs, January 1, 1000000 B.C. and December
+ * 31, 1000000 A.D. in the Julian calendar.  The Gregorian calendar covers
+ * a slightly shorter range, from January 1, 1000000 B.C. to December 31,
+ * 1000000 A.D. in this calendar, the French republican and Jewish
+ * calendars start with their epochs.  All the conversions are correct
+ * over this range.
+ *
+ *     pub const fn Sdn::new(value: i64) -> Result<Sdn, CalendarError>
+ *     pub const fn Sdn::value(self) -> i64
+ *
+ * Create a Sdn, returning OutOfRange if it is outside of the supported
+ * range, and read its value.  Sdn also implements TryFrom<i64>, and i64
+ * implements From<Sdn>.
+ *
+ *     pub fn Sdn::from_date<T: CalendarDate>(date: &T) -> Result<Sdn, CalendarError>
+ *     pub fn Sdn::to_date<T: CalendarDate>(self) -> Result<T, CalendarError>
+ *
+ * Convert a date of any calendar to and from a Sdn, as the to_sdn() and
+ * from_sdn() methods of CalendarDate.  For example:
+ *
+ *    let sdn = Sdn::from_date(&GregorianDate::new(-44, 3, 15)?)?;
+ *    let julian_date: JulianDate = sdn.to_date()?;
+ *
+ *     pub fn Sdn::checked_add(self, days: Days) -> Result<Sdn, CalendarError>
+ *     pub fn Sdn::checked_sub(self, days: Days) -> Result<Sdn, CalendarError>
+ *     pub fn Sdn::days_until(self, other: Sdn) -> Days
+ *
+ * Add or subtract a number of days, returning OutOfRange if the result
+ * is outside of the supported range, and return the number of days from
+ * the SDN to another SDN, which always fits in Days.  The operators +
+ * and - with Days and Sdn - Sdn are also implemented, and they panic when
+ * the result is out of range, as the operators of the dates.
+ *
+ *     pub fn Sdn::day_of_week(self) -> i32
+ *
+ * Convert the SDN to a day-of-week number (0 for Sunday to 6 for
+ * Saturday), as gregorian_calendar::day_of_week().
+ *
+ **************************************************************************/
+
+use core::ops::{Add, Sub};
+
+use crate::calendar::{CalendarDate, Days};
+use crate::error::CalendarError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Sdn(i64);
+
+impl Sdn {
+    pub const MIN: Sdn = Sdn(-363528576);
+    pub const MAX: Sdn = Sdn(366971423);
+
+    pub const fn new(value: i64) -> Result<Sdn, CalendarError> {
+        if value < Sdn::MIN.0 || value > Sdn::MAX.0 {
+            Err(CalendarError::OutOfRange)
+        } else {
+            Ok(Sdn(value))
+        }
+    }
+
+    /* A SDN known to be within the supported range, such as the first SDN
+     * of a calendar. */
+    pub(crate) const fn from_valid(value: i64) -> Sdn {
+        match Sdn::new(value) {
+            Ok(sdn) => sdn,
+            Err(_) => panic!("SDN out of range"),
+        }
+    }
+
+    pub const fn value(self) -> i64 {
+        self.0
+    }
+
+    pub fn from_date<T: CalendarDate>(date: &T) -> Result<Sdn, CalendarError> {
+        date.to_sdn()
+    }
+
+    pub fn to_date<T: CalendarDate>(self) -> Result<T, CalendarError> {
+        T::from_sdn(self)
+    }
+
+    pub fn checked_add(self, days: Days) -> Result<Sdn, CalendarError> {
+        Sdn::new(self.0 + days.0 as i64)
+    }
+
+    pub fn checked_sub(self, days: Days) -> Result<Sdn, CalendarError> {
+        Sdn::new(self.0 - days.0 as i64)
+    }
+
+    pub fn days_until(self, other: Sdn) -> Days {
+        Days((other.0 - self.0) as i32)
+    }
+
+    pub fn day_of_week(self) -> i32 {
+        (self.0 + 1).rem_euclid(7) as i32
+    }
+}
+
+impl TryFrom<i64> for Sdn {
+    type Error = CalendarError;
+
+    fn try_from(value: i64) -> Result<Sdn, CalendarError> {
+        Sdn::new(value)
+    }
+}
+
+impl From<Sdn> for i64 {
+    fn from(sdn: Sdn) -> i64 {
+        sdn.0
+    }
+}
+
+impl Add<Days> for Sdn {
+    type Output = Sdn;
+
+    fn add(self, days: Days) -> Sdn {
+        self.checked_add(days).expect("SDN out of range")
+    }
+}
+
+impl Sub<Days> for Sdn {
+    type Output = Sdn;
+
+    fn sub(self, days: Days) -> Sdn {
+        self.checked_sub(days).expect("SDN out of range")
+    }
+}
+
+impl Sub for Sdn {
+    type Output = Days;
+
+    fn sub(self, other: Sdn) -> Days {
+        other.days_until(self)
+    }
+}
@@ -0,0 +1,176 @@
+/**************************************************************************
+ *
+ * This file is only compiled with the "serde" feature.  These are the
+ * externally visible components of this file:
+ *
+ *     impl Serialize for FrenchDate, GregorianDate, JulianDate, JewishDate
+ *     impl Deserialize for FrenchDate, GregorianDate, JulianDate, JewishDate
+ *
+ * The dates are serialized as a structure with the fields year, month and
+ * day, e.g. {"year": 8, "month": 2, "day": 18} in JSON.  A sequence of the
+ * three numbers is also accepted when deserializing.
+ *
+ *     pub mod compact
+ *
+ * Serialize a date as a compact string instead, by annotating a field
+ * with #[serde(with = "french_republican_calendar::serialization::compact")].
+ * The string is the year on at least 4 digits, the month and the day,
+ * separated by dashes, e.g. "0008-02-18" for 18 Brumaire an VIII or
+ * "1799-11-09" for a Gregorian date, the complementary days being the 13th
+ * month.  For the calendars counting years B.C. and A.D., the year is an
+ * astronomical year as in ISO 8601: 0 is 1 B.C., -1 is 2 B.C. and so on,
+ * e.g. "-0043-03-15" for the Ides of March of 44 B.C.
+ *
+ * In both forms, the date is validated when it is deserialized, and an
+ * invalid date, such as a 6th complementary day of a non-sextile year, is
+ * an error.  The French republican dates use the default leap year rule.
+ *
+ **************************************************************************/
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use crate::calendar::CalendarDate;
+use crate::french_calendar::FrenchDate;
+use crate::gregorian_calendar::GregorianDate;
+use crate::jewish_calendar::JewishDate;
+use crate::julian_calendar::JulianDate;
+
+const FIELDS: &[&str] = &["year", "month", "day"];
+
+fn serialize_struct<T, S>(date: &T, name: &'static str, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: CalendarDate,
+    S: Serializer,
+{
+    let mut state = serializer.serialize_struct(name, 3)?;
+    state.serialize_field("year", &date.year())?;
+    state.serialize_field("month", &date.month())?;
+    state.serialize_field("day", &date.day())?;
+    state.end()
+}
+
+/* The index of a field of the structure, deserialized from its name without
+ * allocating it. */
+struct Field(usize);
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Field, D::Error> {
+        struct FieldVisitor;
+
+        impl Visitor<'_> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("year, month or day")
+            }
+
+            fn visit_str<E: de::Error>(self, key: &str) -> Result<Field, E> {
+                FIELDS.iter().position(|&field| field == key).map(Field)
+                    .ok_or_else(|| de::Error::unknown_field(key, FIELDS))
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct DateVisitor<T>(&'static str, PhantomData<T>);
+
+impl<'de, T: CalendarDate> Visitor<'de> for DateVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a {} with a year, a month and a day", self.0)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        let mut next = |index| seq.next_element()?.ok_or_else(|| de::Error::invalid_length(index, &self));
+        let (year, month, day) = (next(0)?, next(1)?, next(2)?);
+        T::from_ymd(year, month, day).map_err(de::Error::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<T, A::Error> {
+        let mut values: [Option<i32>; 3] = [None; 3];
+        while let Some(Field(index)) = map.next_key()? {
+            if values[index].is_some() {
+                return Err(de::Error::duplicate_field(FIELDS[index]));
+            }
+            values[index] = Some(map.next_value()?);
+        }
+        let value = |index: usize| values[index].ok_or_else(|| de::Error::missing_field(FIELDS[index]));
+        T::from_ymd(value(0)?, value(1)?, value(2)?).map_err(de::Error::custom)
+    }
+}
+
+macro_rules! impl_date_serde {
+    ($date:ident) => {
+        impl Serialize for $date {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_struct(self, stringify!($date), serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $date {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<$date, D::Error> {
+                let visitor = DateVisitor(stringify!($date), PhantomData);
+                deserializer.deserialize_struct(stringify!($date), FIELDS, visitor)
+            }
+        }
+    };
+}
+
+impl_date_serde!(FrenchDate);
+impl_date_serde!(GregorianDate);
+impl_date_serde!(JulianDate);
+impl_date_serde!(JewishDate);
+
+pub mod compact {
+    use core::fmt;
+    use core::marker::PhantomData;
+
+    use serde::de::{self, Deserializer, Visitor};
+    use serde::ser::Serializer;
+
+    use crate::calendar::CalendarDate;
+    use crate::literal::parse_iso;
+
+    pub fn serialize<T, S>(date: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: CalendarDate,
+        S: Serializer,
+    {
+        let year = if date.year() < 0 {date.year() + 1} else {date.year()};
+        let sign = if year < 0 {"-"} else if year > 9999 {"+"} else {""};
+        serializer.collect_str(&format_args!(
+            "{}{:04}-{:02}-{:02}", sign, year.unsigned_abs(), date.month(), date.day()
+        ))
+    }
+
+    struct CompactVisitor<T>(PhantomData<T>);
+
+    impl<T: CalendarDate> Visitor<'_> for CompactVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a date formatted as YYYY-MM-DD")
+        }
+
+        fn visit_str<E: de::Error>(self, text: &str) -> Result<T, E> {
+            let (year, month, day) = parse_iso(text)
+                .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(text), &self))?;
+            T::from_ymd(year, month, day).map_err(de::Error::custom)
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: CalendarDate,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(CompactVisitor(PhantomData))
+    }
+}
@@ -0,0 +1,21 @@
+use french_republican_calendar::astronomy;
+
+#[test]
+pub fn test_autumn_equinoxes() {
+    /* Julian dates (UT) of published September equinoxes. */
+    const EQUINOXES: [(i32, f64); 3] = [
+        (2000, 2451810.2271),   /* 22 Sep 2000 17:27 */
+        (2023, 2460210.7847),   /* 23 Sep 2023 06:50 */
+        (2024, 2460576.0306),   /* 22 Sep 2024 12:44 */
+    ];
+
+    println!("Verifying {} known September equinoxes", EQUINOXES.len());
+
+    for &(year, expected) in EQUINOXES.iter() {
+        let jd = astronomy::autumn_equinox(year);
+        assert!((jd - expected).abs() < 2.0 / 1440.0, "{}: {} != {}", year, jd, expected);
+    }
+
+    assert!((astronomy::delta_t(2000.0) - 63.86).abs() < 0.01);
+    assert!((astronomy::equation_of_time(2451810.0) * 1440.0 - 7.2).abs() < 0.5);
+}
@@ -0,0 +1,148 @@
+use std::fmt::Debug;
+
+use french_republican_calendar::{
+    convert, french_calendar::{FrenchDate, LeapRule}, gregorian_calendar::GregorianDate,
+    jewish_calendar::JewishDate, julian_calendar::JulianDate, CalendarDate, CalendarError,
+    ClampPolicy, Days,
+};
+
+/* Check the conversions and the calendar properties of the days from the
+ * first SDN of the calendar on. */
+fn verify_calendar<T: CalendarDate + Debug + PartialEq>(name: &str, days: i32) {
+    println!("Verifying {} days of the {} calendar through CalendarDate", days, name);
+
+    let first = *T::valid_range().start();
+    if let Ok(before) = first.checked_sub(Days(1)) {
+        assert!(T::from_sdn(before) == Err(CalendarError::OutOfRange), "{}", name);
+    }
+
+    let mut date = T::from_sdn(first).unwrap();
+    for n in 0..days {
+        let sdn = first + Days(n);
+        let date2 = T::from_sdn(sdn).unwrap();
+        assert!(date == date2 && date.to_sdn() == Ok(sdn), "{:?} {:?}   erroneous: {:?}", sdn, date, date2);
+        assert!(T::from_ymd(date.year(), date.month(), date.day()).as_ref() == Ok(&date));
+        assert!(date.day() <= T::days_in_month(date.year(), date.month()), "{:?}", date);
+        assert!(!T::month_name(date.year(), date.month()).is_empty(), "{:?}", date);
+        date.next_day();
+    }
+}
+
+#[test]
+pub fn test_calendar_dates() {
+    verify_calendar::<FrenchDate>("French republican", 100000);
+    verify_calendar::<GregorianDate>("Gregorian", 100000);
+    verify_calendar::<JulianDate>("Julian", 100000);
+    verify_calendar::<JewishDate>("Jewish", 100000);
+}
+
+#[test]
+pub fn test_calendar_properties() {
+    println!("Verifying the leap years and the month lengths");
+
+    assert!(GregorianDate::is_leap_year(2000) && !GregorianDate::is_leap_year(1900));
+    assert!(JulianDate::is_leap_year(2000) && JulianDate::is_leap_year(1900));
+    assert!(FrenchDate::is_leap_year(3) && !FrenchDate::is_leap_year(4));
+    assert!(JewishDate::is_leap_year(5784) && !JewishDate::is_leap_year(5785));
+
+    assert!(GregorianDate::days_in_month(1900, 2) == 28);
+    assert!(JulianDate::days_in_month(1900, 2) == 29);
+    assert!(FrenchDate::days_in_month(3, 13) == 6);
+    assert!(FrenchDate::days_in_month(4, 13) == 5);
+    assert!(JewishDate::days_in_month(5785, 7) == 0);
+    assert!(GregorianDate::days_in_month(1900, 13) == 0);
+
+    assert!(GregorianDate::month_name(1799, 11) == "November");
+    assert!(FrenchDate::month_name(8, 2) == "Brumaire");
+    assert!(JewishDate::month_name(5785, 6) == "Adar");
+    assert!(JulianDate::month_name(1799, 0).is_empty());
+}
+
+#[test]
+pub fn test_calendar_conversions() {
+    println!("Verifying the conversions between calendars");
+
+    /* 18 Brumaire an VIII, the coup d'état of Napoléon Bonaparte. */
+    let g_date = GregorianDate::new(1799, 11, 9).unwrap();
+    let f_date: FrenchDate = convert(&g_date).unwrap();
+    assert!(f_date == FrenchDate::new(8, 2, 18).unwrap());
+    let j_date: JulianDate = convert(&f_date).unwrap();
+    assert!(j_date == JulianDate::new(1799, 10, 29).unwrap());
+    let h_date: JewishDate = convert(&j_date).unwrap();
+    assert!(h_date == JewishDate::new(5560, 2, 11).unwrap(), "{:?}", h_date);
+    let g_date2: GregorianDate = convert(&h_date).unwrap();
+    assert!(g_date2 == g_date);
+
+    let g_date = GregorianDate::new(1700, 1, 1).unwrap();
+    assert!(convert::<_, FrenchDate>(&g_date) == Err(CalendarError::OutOfRange));
+}
+
+#[test]
+pub fn test_calendar_arithmetic() {
+    println!("Verifying the arithmetic on dates");
+
+    let g_date = GregorianDate::new(1799, 11, 9).unwrap();
+    assert!(g_date + Days(53) == GregorianDate::new(1800, 1, 1).unwrap());
+    assert!(g_date - Days(365) == GregorianDate::new(1798, 11, 9).unwrap());
+    assert!(GregorianDate::new(1800, 1, 1).unwrap() - g_date == Days(53));
+    assert!(g_date.days_until(&GregorianDate::new(1798, 11, 9).unwrap()) == Days(-365));
+    assert!(g_date.add_days(Days(i32::MAX)) == Err(CalendarError::OutOfRange));
+
+    /* The 6th complementary day of the year XIX only exists under the
+     * Continuous rule, so it has no SDN under the default rule. */
+    let continuous = FrenchDate::new_with_rule(19, 13, 6, LeapRule::Continuous).unwrap();
+    let f_date = FrenchDate::new(20, 1, 1).unwrap();
+    assert!(continuous.try_days_until(&f_date) == Err(CalendarError::NoSixthSansculottide));
+    assert!(f_date.try_days_until(&continuous) == Err(CalendarError::NoSixthSansculottide));
+    assert!(FrenchDate::new(19, 13, 5).unwrap().try_days_until(&f_date) == Ok(Days(1)));
+    assert!(std::panic::catch_unwind(|| f_date - continuous).is_err());
+
+    let mut f_date = FrenchDate::new(2, 1, 1).unwrap();
+    f_date.prev_day();
+    assert!(f_date == FrenchDate::new(1, 13, 5).unwrap());
+    f_date += Days(10);
+    assert!(f_date == FrenchDate::new(2, 1, 10).unwrap());
+    f_date -= Days(10);
+    assert!(f_date == FrenchDate::new(1, 13, 5).unwrap());
+
+    println!("Verifying the chronological order of dates");
+
+    let mut dates: Vec<FrenchDate> = (0..1000).rev().map(|n| f_date + Days(n * 7)).collect();
+    dates.sort();
+    assert!(dates.windows(2).all(|pair| pair[0].to_sdn().unwrap() < pair[1].to_sdn().unwrap()));
+    assert!(FrenchDate::new(3, 12, 30).unwrap() < FrenchDate::new(3, 13, 1).unwrap());
+    assert!(FrenchDate::new(3, 13, 6).unwrap() < FrenchDate::new(4, 1, 1).unwrap());
+    assert!(JewishDate::new(5784, 6, 30).unwrap() < JewishDate::new(5784, 7, 1).unwrap());
+    let set: std::collections::HashSet<GregorianDate> = (0..100).map(|n| g_date + Days(n % 10)).collect();
+    assert!(set.len() == 10);
+
+    println!("Verifying the addition of months and years");
+
+    let g_date = GregorianDate::new(2023, 1, 31).unwrap();
+    assert!(g_date.add_months(1, ClampPolicy::Clamp) == GregorianDate::new(2023, 2, 28));
+    assert!(g_date.add_months(1, ClampPolicy::Spill) == GregorianDate::new(2023, 3, 3));
+    assert!(g_date.add_months(1, ClampPolicy::Strict) == Err(CalendarError::InvalidDay));
+    assert!(g_date.add_months(-25, ClampPolicy::Strict) == GregorianDate::new(2020, 12, 31));
+    assert!(g_date.add_months(13, ClampPolicy::Clamp) == GregorianDate::new(2024, 2, 29));
+    assert!(GregorianDate::new(1, 3, 1).unwrap().add_years(-1, ClampPolicy::Strict) == GregorianDate::new(-1, 3, 1));
+    assert!(GregorianDate::new(-1, 3, 1).unwrap().add_months(12, ClampPolicy::Strict) == GregorianDate::new(1, 3, 1));
+
+    /* The 6th complementary day of year 3 is the Jour de la Révolution. */
+    let f_date = FrenchDate::new(3, 13, 6).unwrap();
+    assert!(f_date.add_years(1, ClampPolicy::Clamp) == FrenchDate::new(4, 13, 5));
+    assert!(f_date.add_years(1, ClampPolicy::Spill) == FrenchDate::new(5, 1, 1));
+    assert!(f_date.add_years(1, ClampPolicy::Strict) == Err(CalendarError::NoSixthSansculottide));
+    assert!(f_date.add_years(4, ClampPolicy::Strict) == FrenchDate::new(7, 13, 6));
+    assert!(f_date.add_years(-3, ClampPolicy::Strict) == Err(CalendarError::OutOfRange));
+    let f_date = FrenchDate::new(8, 12, 30).unwrap();
+    assert!(f_date.add_months(1, ClampPolicy::Clamp) == FrenchDate::new(8, 13, 5));
+    assert!(f_date.add_months(2, ClampPolicy::Strict) == FrenchDate::new(9, 1, 30));
+
+    let j_date = JewishDate::new(5784, 7, 15).unwrap();
+    assert!(j_date.add_years(1, ClampPolicy::Clamp) == JewishDate::new(5785, 6, 15));
+    assert!(j_date.add_years(1, ClampPolicy::Strict) == Err(CalendarError::InvalidMonth));
+    assert!(j_date.add_months(1, ClampPolicy::Strict) == JewishDate::new(5784, 8, 15));
+    let j_date = JewishDate::new(5785, 6, 15).unwrap();
+    assert!(j_date.add_months(1, ClampPolicy::Strict) == JewishDate::new(5785, 8, 15));
+    assert!(j_date.add_months(-13, ClampPolicy::Strict) == JewishDate::new(5784, 6, 15));
+}
@@ -0,0 +1,70 @@
+use french_republican_calendar::clock::{FixedClock, UtcOffset};
+use french_republican_calendar::day_count::sdn_to_unix;
+use french_republican_calendar::decimal_time::StandardTime;
+use french_republican_calendar::french_calendar::FrenchDate;
+use french_republican_calendar::gregorian_calendar::GregorianDate;
+use french_republican_calendar::{CalendarError, Sdn};
+
+/* The Unix time of a Gregorian date and time in UTC. */
+fn unix_seconds(year: i32, month: i32, day: i32, hour: i32, minute: i32) -> i64 {
+    let sdn = Sdn::from_date(&GregorianDate::new(year, month, day).unwrap()).unwrap();
+    sdn_to_unix(sdn, &StandardTime::new(hour, minute, 0).unwrap())
+}
+
+#[test]
+pub fn test_today_with_clock() {
+    println!("Verifying today's date from a fixed clock");
+
+    let check = |clock: FixedClock, offset: UtcOffset, gregorian: (i32, i32, i32), french: (i32, i32, i32)| {
+        let g_date = GregorianDate::today_with_clock(&clock, offset).unwrap();
+        assert!((g_date.year(), g_date.month(), g_date.day()) == gregorian,
+                "{:?} {:?}   erroneous: {:?}", clock, offset, g_date);
+        let f_date = FrenchDate::today_with_clock(&clock, offset).unwrap();
+        assert!((f_date.year(), f_date.month(), f_date.day()) == french,
+                "{:?} {:?}   erroneous: {:?}", clock, offset, f_date);
+    };
+
+    /* 18 Brumaire an VIII starts at 23:50:39 UTC in Paris Mean Time. */
+    let clock = FixedClock(unix_seconds(1799, 11, 8, 23, 55));
+    check(clock, UtcOffset::UTC, (1799, 11, 8), (8, 2, 17));
+    check(clock, UtcOffset::PARIS_MEAN_TIME, (1799, 11, 9), (8, 2, 18));
+    let clock = FixedClock(unix_seconds(1799, 11, 8, 23, 50));
+    check(clock, UtcOffset::PARIS_MEAN_TIME, (1799, 11, 8), (8, 2, 17));
+
+    let clock = FixedClock(unix_seconds(1794, 7, 27, 3, 0));
+    check(clock, UtcOffset::new(-5 * 3600).unwrap(), (1794, 7, 26), (2, 11, 8));
+    check(clock, UtcOffset::new(3600).unwrap(), (1794, 7, 27), (2, 11, 9));
+
+    /* The republican calendar only starts in 1792. */
+    let clock = FixedClock(unix_seconds(1789, 7, 14, 12, 0));
+    assert!(FrenchDate::today_with_clock(&clock, UtcOffset::UTC) == Err(CalendarError::OutOfRange));
+    assert!(GregorianDate::today_with_clock(&clock, UtcOffset::UTC).is_ok());
+    assert!(GregorianDate::today_with_clock(&FixedClock(i64::MAX), UtcOffset::PARIS_MEAN_TIME)
+            == Err(CalendarError::OutOfRange));
+}
+
+#[test]
+pub fn test_utc_offset() {
+    println!("Verifying the offsets from UTC");
+
+    assert!(UtcOffset::PARIS_MEAN_TIME.seconds() == 561);
+    assert!(UtcOffset::UTC.seconds() == 0);
+    assert!(UtcOffset::new(-86399).map(UtcOffset::seconds) == Ok(-86399));
+    assert!(UtcOffset::new(86400) == Err(CalendarError::OutOfRange));
+    assert!(UtcOffset::new(-86400) == Err(CalendarError::OutOfRange));
+}
+
+#[cfg(feature = "std")]
+#[test]
+pub fn test_today() {
+    use french_republican_calendar::clock::{Clock, SystemClock};
+
+    println!("Verifying today's date from the system clock");
+
+    let g_date = GregorianDate::today(UtcOffset::UTC).unwrap();
+    let f_date = FrenchDate::today(UtcOffset::UTC).unwrap();
+    assert!(g_date.year() >= 2024, "erroneous: {:?}", g_date);
+    let year = f_date.year() + 1791;
+    assert!(year == g_date.year() || year == g_date.year() + 1, "erroneous: {:?}", f_date);
+    assert!(SystemClock.unix_seconds() > 1_700_000_000);
+}
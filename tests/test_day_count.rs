@@ -0,0 +1,125 @@
+use french_republican_calendar::day_count::*;
+use french_republican_calendar::decimal_time::StandardTime;
+use french_republican_calendar::gregorian_calendar::GregorianDate;
+use french_republican_calendar::julian_calendar::JulianDate;
+use french_republican_calendar::{CalendarError, Days, Sdn};
+
+fn gregorian(year: i32, month: i32, day: i32) -> Sdn {
+    Sdn::from_date(&GregorianDate::new(year, month, day).unwrap()).unwrap()
+}
+
+fn time(hour: i32, minute: i32, second: i32) -> StandardTime {
+    StandardTime::new(hour, minute, second).unwrap()
+}
+
+#[test]
+pub fn test_julian_day() {
+    println!("Verifying the conversions to and from fractional Julian Dates");
+
+    let check = |julian_day: f64, sdn: Sdn, time: StandardTime| {
+        let result = julian_day_to_sdn(julian_day);
+        assert!(result == Ok((sdn, time)), "JD {}   erroneous: {:?}", julian_day, result);
+        let back = sdn_to_julian_day(sdn, &time);
+        assert!(back == julian_day, "{:?} {}   erroneous JD: {}", sdn, time, back);
+    };
+
+    /* The Julian Date starts at noon. */
+    check(2451545.0, gregorian(2000, 1, 1), time(12, 0, 0));
+    check(2451544.5, gregorian(2000, 1, 1), time(0, 0, 0));
+    check(2451545.25, gregorian(2000, 1, 1), time(18, 0, 0));
+    check(2451545.5, gregorian(2000, 1, 2), time(0, 0, 0));
+    check(2440587.5, gregorian(1970, 1, 1), time(0, 0, 0));
+    check(0.0, Sdn::from_date(&JulianDate::new(-4713, 1, 1).unwrap()).unwrap(), time(12, 0, 0));
+    check(-0.5, Sdn::new(0).unwrap(), time(0, 0, 0));
+    check(-1.0, Sdn::new(-1).unwrap(), time(12, 0, 0));
+
+    /* The time is rounded to the millisecond, possibly to the next day. */
+    let result = julian_day_to_sdn(2451545.0 + 1.0 / 86_400.0);
+    assert!(result == Ok((gregorian(2000, 1, 1), time(12, 0, 1))), "erroneous: {:?}", result);
+    let result = julian_day_to_sdn(2451545.5 - 1e-9);
+    assert!(result == Ok((gregorian(2000, 1, 2), time(0, 0, 0))), "erroneous: {:?}", result);
+    let precise = StandardTime::with_nanosecond(6, 30, 15, 250_000_000).unwrap();
+    let result = julian_day_to_sdn(sdn_to_julian_day(gregorian(1794, 7, 27), &precise));
+    assert!(result == Ok((gregorian(1794, 7, 27), precise)), "erroneous: {:?}", result);
+
+    assert!(julian_day_to_sdn(f64::NAN) == Err(CalendarError::OutOfRange));
+    assert!(julian_day_to_sdn(f64::INFINITY) == Err(CalendarError::OutOfRange));
+    assert!(julian_day_to_sdn(1e12) == Err(CalendarError::OutOfRange));
+    assert!(julian_day_to_sdn(-1e12) == Err(CalendarError::OutOfRange));
+}
+
+#[test]
+pub fn test_day_counts() {
+    println!("Verifying the Modified Julian Dates, Rata Die and Unix times");
+
+    assert!(sdn_to_mjd(gregorian(1858, 11, 17)) == 0);
+    assert!(sdn_to_mjd(gregorian(2000, 1, 1)) == 51544);
+    assert!(mjd_to_sdn(51544) == Ok(gregorian(2000, 1, 1)));
+    assert!(mjd_to_sdn(-1) == Ok(gregorian(1858, 11, 16)));
+    assert!(mjd_to_sdn(i64::MAX) == Err(CalendarError::OutOfRange));
+
+    assert!(sdn_to_rata_die(gregorian(1, 1, 1)) == 1);
+    assert!(sdn_to_rata_die(gregorian(1945, 11, 12)) == 710347);
+    assert!(rata_die_to_sdn(730120) == Ok(gregorian(2000, 1, 1)));
+    assert!(rata_die_to_sdn(0) == Ok(gregorian(-1, 12, 31)));
+    assert!(rata_die_to_sdn(i64::MIN) == Err(CalendarError::OutOfRange));
+
+    let check = |seconds: i64, sdn: Sdn, time: StandardTime| {
+        let result = unix_to_sdn(seconds);
+        assert!(result == Ok((sdn, time)), "Unix {}   erroneous: {:?}", seconds, result);
+        let back = sdn_to_unix(sdn, &time);
+        assert!(back == seconds, "{:?} {}   erroneous Unix time: {}", sdn, time, back);
+    };
+    check(0, gregorian(1970, 1, 1), time(0, 0, 0));
+    check(-1, gregorian(1969, 12, 31), time(23, 59, 59));
+    check(946684800, gregorian(2000, 1, 1), time(0, 0, 0));
+    check(1234567890, gregorian(2009, 2, 13), time(23, 31, 30));
+    check(-5364662400, gregorian(1800, 1, 1), time(0, 0, 0));
+
+    let precise = StandardTime::with_nanosecond(0, 0, 1, 999_999_999).unwrap();
+    assert!(sdn_to_unix(gregorian(1970, 1, 1), &precise) == 1);
+    assert!(unix_to_sdn(i64::MAX) == Err(CalendarError::OutOfRange));
+}
+
+#[test]
+pub fn test_spreadsheet_serials() {
+    println!("Verifying the serial dates of the spreadsheets");
+
+    let check_1900 = |serial: i64, sdn: Sdn| {
+        let result = excel_1900_to_sdn(serial);
+        assert!(result == Ok(sdn), "1900 serial {}   erroneous: {:?}", serial, result);
+        let back = sdn_to_excel_1900(sdn);
+        assert!(back == Ok(serial), "{:?}   erroneous 1900 serial: {:?}", sdn, back);
+    };
+    check_1900(1, gregorian(1900, 1, 1));
+    check_1900(59, gregorian(1900, 2, 28));
+    check_1900(61, gregorian(1900, 3, 1));
+    check_1900(36526, gregorian(2000, 1, 1));
+    check_1900(45000, gregorian(2023, 3, 15));
+    check_1900(2958465, gregorian(9999, 12, 31));
+
+    /* The fake 29 February 1900 of Lotus 1-2-3. */
+    assert!(excel_1900_to_sdn(60) == Err(CalendarError::InvalidDay));
+    assert!(excel_1900_to_sdn(0) == Err(CalendarError::OutOfRange));
+    assert!(excel_1900_to_sdn(2958466) == Err(CalendarError::OutOfRange));
+    assert!(sdn_to_excel_1900(gregorian(1899, 12, 31)) == Err(CalendarError::OutOfRange));
+    assert!(sdn_to_excel_1900(gregorian(10000, 1, 1)) == Err(CalendarError::OutOfRange));
+
+    let check_1904 = |serial: i64, sdn: Sdn| {
+        let result = excel_1904_to_sdn(serial);
+        assert!(result == Ok(sdn), "1904 serial {}   erroneous: {:?}", serial, result);
+        let back = sdn_to_excel_1904(sdn);
+        assert!(back == Ok(serial), "{:?}   erroneous 1904 serial: {:?}", sdn, back);
+    };
+    check_1904(0, gregorian(1904, 1, 1));
+    check_1904(35064, gregorian(2000, 1, 1));
+    check_1904(2957003, gregorian(9999, 12, 31));
+
+    /* The two systems differ by 1462 days from March 1, 1900. */
+    let sdn = gregorian(1799, 11, 9).checked_add(Days(100000)).unwrap();
+    assert!(sdn_to_excel_1900(sdn).unwrap() - sdn_to_excel_1904(sdn).unwrap() == 1462);
+
+    assert!(excel_1904_to_sdn(-1) == Err(CalendarError::OutOfRange));
+    assert!(excel_1904_to_sdn(2957004) == Err(CalendarError::OutOfRange));
+    assert!(sdn_to_excel_1904(gregorian(1903, 12, 31)) == Err(CalendarError::OutOfRange));
+}
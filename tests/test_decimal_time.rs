@@ -0,0 +1,51 @@
+use french_republican_calendar::decimal_time::{self, DecimalTime, StandardTime};
+
+#[test]
+pub fn test_decimal_time() {
+    println!("Verifying all the seconds of a day");
+
+    let mut previous = None;
+    for seconds in 0..86400 {
+        let time = StandardTime::new(seconds / 3600, seconds / 60 % 60, seconds % 60).unwrap();
+        let decimal = decimal_time::standard_to_decimal(&time);
+        assert!(decimal_time::decimal_to_standard(&decimal) == time, "{} {}", time, decimal);
+        assert!(previous < Some(decimal), "{} {}", time, decimal);
+        previous = Some(decimal);
+
+        let decimal = decimal.round_to_second();
+        assert!(decimal_time::decimal_to_standard(&decimal).round_to_second() == time, "{} {}", time, decimal);
+    }
+
+    println!("Verifying known decimal times");
+
+    let check = |standard: &str, hour, minute, second| {
+        let time: StandardTime = standard.parse().unwrap();
+        let decimal = decimal_time::standard_to_decimal(&time);
+        assert!(decimal == DecimalTime::new(hour, minute, second).unwrap() && decimal.nanosecond() == 0,
+            "{} {}", standard, decimal);
+        assert!(decimal_time::decimal_to_standard(&decimal) == time, "{} {}", standard, decimal);
+    };
+
+    check("00:00", 0, 0, 0);
+    check("02:24", 1, 0, 0);
+    check("12:00:00", 5, 0, 0);
+    check("13:43:40.8", 5, 72, 0);
+    check("18:00", 7, 50, 0);
+    check("00:00:00.864", 0, 0, 1);
+
+    let time: StandardTime = "23:59:59.999999999".parse().unwrap();
+    let decimal = decimal_time::standard_to_decimal(&time);
+    assert!(decimal.hour() == 9 && decimal.minute() == 99 && decimal.second() == 99);
+    assert!(decimal_time::decimal_to_standard(&decimal) == time);
+    assert!(decimal.round_to_second() == DecimalTime::new(9, 99, 99).unwrap());
+    assert!(time.round_to_second() == StandardTime::new(23, 59, 59).unwrap());
+    let last = DecimalTime::with_nanosecond(9, 99, 99, 999_999_999).unwrap();
+    assert!(decimal_time::decimal_to_standard(&last) == time);
+    assert!(decimal_time::decimal_to_standard(&last).to_string() == "23:59:59.999999999");
+
+    assert!(DecimalTime::new(5, 43, 20).unwrap().to_string() == "5h 43m 20s décimales");
+    assert!(StandardTime::with_nanosecond(13, 43, 40, 800_000_000).unwrap().to_string() == "13:43:40.8");
+    assert!(DecimalTime::new(10, 0, 0).is_err() && StandardTime::new(24, 0, 0).is_err());
+    assert!("13".parse::<StandardTime>().is_err() && "13:4a".parse::<StandardTime>().is_err());
+    assert!("13:43.5".parse::<StandardTime>().is_err() && "13:43:40:1".parse::<StandardTime>().is_err());
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "alloc")]
+
+use french_republican_calendar::{french_calendar::FrenchDate, gregorian_calendar::GregorianDate};
+
+#[test]
+pub fn test_format_french() {
+    println!("Verifying the formatting of French republican dates");
+
+    let check = |f_date: &FrenchDate, format, expected: &str| {
+        let formatted = f_date.format(format).to_string();
+        assert!(formatted == expected, "{:?} {:?}   erroneous: {:?}", f_date, format, formatted);
+    };
+
+    let f_date = FrenchDate::new(2, 2, 20).unwrap();
+    check(&f_date, "%A %d %B an %Y", "Décadi 20 Brumaire an II");
+    check(&f_date, "%a %m/%y %j %u %D", "Déc 02/2 050 10 05");
+    check(&f_date, "%R", "Herse");
+    check(&f_date, "[%S]", "[]");
+
+    let f_date = FrenchDate::new(8, 2, 3).unwrap();
+    check(&f_date, "%d|%-d|%_d|%e|%4d|%_4d|%-4d", "03|3| 3| 3|0003|   3|3");
+    check(&f_date, "%^B|%10B|%^a|%-m", "BRUMAIRE|  Brumaire|TRI|2");
+    check(&f_date, "%y %Y %_5y", "8 VIII     8");
+    check(&f_date, "100%% %n%t%Q %", "100% \n\t%Q %");
+    assert!(f_date.to_string() == "Tridi 3 Brumaire an VIII");
+
+    let f_date = FrenchDate::new(3, 13, 6).unwrap();
+    check(&f_date, "%A|%a|%S|%R|%B|%d|%j", "Jour de la Révolution|Révolution|Révolution|Jour de la Révolution|Extra|06|366");
+    check(&f_date, "[%u][%D]", "[][]");
+    assert!(f_date.to_string() == "Jour de la Révolution an III");
+    assert!(format!("{:*<30}|{:.7}", f_date, f_date) == "Jour de la Révolution an III**|Jour de");
+
+    let f_date = FrenchDate::new(4000, 1, 1).unwrap();
+    check(&f_date, "an %Y", "an 4000");
+}
+
+#[test]
+pub fn test_format_gregorian() {
+    println!("Verifying the formatting of Gregorian dates");
+
+    let check = |g_date: &GregorianDate, format, expected: &str| {
+        let formatted = g_date.format(format).to_string();
+        assert!(formatted == expected, "{:?} {:?}   erroneous: {:?}", g_date, format, formatted);
+    };
+
+    let g_date = GregorianDate::new(1799, 11, 9).unwrap();
+    check(&g_date, "%A %d %B %Y", "Saturday 09 November 1799");
+    check(&g_date, "%a %b %e %y %j %u %w", "Sat Nov  9 99 313 6 6");
+    check(&g_date, "%-d %B %_6Y", "9 November   1799");
+    assert!(g_date.to_string() == "Saturday 9 November 1799");
+    assert!(format!("[{}]", g_date) == "[Saturday 9 November 1799]");
+    assert!(format!("[{:>30}]", g_date) == "[      Saturday 9 November 1799]");
+    assert!(format!("[{:^28}]", g_date) == "[  Saturday 9 November 1799  ]");
+    assert!(format!("[{:10}]", g_date) == "[Saturday 9 November 1799]");
+
+    let g_date = GregorianDate::new(2000, 12, 31).unwrap();
+    check(&g_date, "%Y-%m-%d %j %u %w", "2000-12-31 366 7 0");
+
+    let g_date = GregorianDate::new(-45, 1, 1).unwrap();
+    check(&g_date, "%Y %05Y %y", "-45 -0045 55");
+
+    let g_date = GregorianDate::new(-5000, 3, 1).unwrap();
+    check(&g_date, "%Y-%m-%d %j", "-5000-03-01 060");
+}
@@ -1,4 +1,4 @@
-use repub_cli::{french_calendar, gregorian_calendar};
+use french_republican_calendar::{french_calendar, gregorian_calendar, Sdn};
 
 /* $selId: vfrench.c,v 2.0 1995/10/24 01:13:06 lees Exp $
  * Copyright 1993-1995, Scott E. Lee, all rights reserved.
@@ -17,10 +17,10 @@ pub fn test_french_dates() {
 
     for (i, &year_start) in YEAR_START.iter().enumerate() {
         let ii: i32 = i as i32;
-        let f_date = french_calendar::FrenchDate{year: ii+1, month: 1, day: 1};
+        let f_date = french_calendar::FrenchDate::new(ii+1, 1, 1).unwrap();
         let f_sdn = french_calendar::french_to_sdn(&f_date);
 
-        let g_date = gregorian_calendar::GregorianDate{year: ii+1792, month: 9, day: year_start};
+        let g_date = gregorian_calendar::GregorianDate::new(ii+1792, 9, year_start).unwrap();
         let g_sdn = gregorian_calendar::gregorian_to_sdn(&g_date);
 
         assert!(f_sdn == g_sdn, "error: {:?}={} != {:?}={}", f_date, f_sdn, g_date, g_sdn);
@@ -29,7 +29,7 @@ pub fn test_french_dates() {
     println!("Verifying all French republican calendar dates from the year {} to {}", 1, 14);
 
     let mut sdn = 2375840;
-    let mut f_date2 = french_calendar::FrenchDate{day: 1, month: 1, year: 1};
+    let mut f_date2 = french_calendar::FrenchDate::new(1, 1, 1).unwrap();
 
     loop {
         let sdn2 = french_calendar::french_to_sdn(&f_date2);
@@ -39,8 +39,254 @@ pub fn test_french_dates() {
         sdn += 1;
         f_date2.next_day();
 
-        if f_date2.year > 14 {
+        if f_date2.year() > 14 {
             break;
         }
     }
 }
+
+#[test]
+pub fn test_french_leap_rules() {
+    use french_calendar::{FrenchDate, LeapRule};
+    use french_republican_calendar::CalendarError;
+
+    const RULES: [LeapRule; 4] = [
+        LeapRule::Continuous, LeapRule::Sextile, LeapRule::Romme, LeapRule::VonMadler,
+    ];
+
+    println!("Verifying that every leap year rule agrees from the year {} to {}", 1, 14);
+
+    for &rule in RULES.iter() {
+        for sdn in 2375840..=2380952 {
+            let f_date = french_calendar::sdn_to_french_with_rule(sdn, rule);
+            assert!(f_date == french_calendar::sdn_to_french(sdn), "{:?} {} {:?}", rule, sdn, f_date);
+            assert!(french_calendar::french_to_sdn_with_rule(&f_date, rule) == sdn, "{:?} {} {:?}", rule, sdn, f_date);
+        }
+    }
+
+    println!("Verifying the sextile years of each rule");
+
+    let sextiles = |rule: LeapRule| (1..=500).filter(|&y| rule.is_sextile(y)).collect::<Vec<i32>>();
+    assert!(sextiles(LeapRule::Continuous)[..6] == [3, 7, 11, 15, 19, 23]);
+    assert!(sextiles(LeapRule::Sextile)[..6] == [3, 7, 11, 15, 20, 24]);
+    assert!(sextiles(LeapRule::Romme)[..6] == [3, 7, 11, 15, 20, 24]);
+    assert!(sextiles(LeapRule::VonMadler)[..6] == [3, 7, 11, 15, 20, 24]);
+    assert!(LeapRule::Sextile.is_sextile(100) && LeapRule::Sextile.is_sextile(128));
+    assert!(!LeapRule::Romme.is_sextile(100) && LeapRule::Romme.is_sextile(400));
+    assert!(!LeapRule::Romme.is_sextile(4000) && LeapRule::Romme.is_sextile(128));
+    assert!(LeapRule::default() == LeapRule::Romme);
+    assert!(FrenchDate::new(100, 13, 6) == Err(CalendarError::NoSixthSansculottide));
+    assert!(FrenchDate::new_with_rule(100, 13, 6, LeapRule::default()) == Err(CalendarError::NoSixthSansculottide));
+    assert!(FrenchDate::new_with_rule(100, 13, 6, LeapRule::Sextile).is_ok());
+    assert!(!LeapRule::VonMadler.is_sextile(128) && LeapRule::VonMadler.is_sextile(100));
+
+    let f_date = french_calendar::FrenchDate::new_with_rule(19, 13, 6, LeapRule::Continuous).unwrap();
+    assert!(french_calendar::french_to_sdn_with_rule(&f_date, LeapRule::Continuous) > 0);
+    assert!(french_calendar::french_to_sdn_with_rule(&f_date, LeapRule::Romme) == 0);
+
+    for &rule in RULES.iter() {
+        println!("Verifying all French republican calendar dates from the year {} to {} ({:?})", 1, 10000, rule);
+
+        let mut sdn = 2375840;
+        let mut f_date2 = french_calendar::FrenchDate::new(1, 1, 1).unwrap();
+
+        loop {
+            let sdn2 = french_calendar::french_to_sdn_with_rule(&f_date2, rule);
+            let f_date = french_calendar::sdn_to_french_with_rule(sdn, rule);
+            assert!(sdn2 == sdn && f_date == f_date2, "{} {:?}   erroneous: {} {:?}", sdn, f_date, sdn2, f_date2);
+
+            sdn += 1;
+            f_date2.next_day_with_rule(rule);
+
+            if f_date2.year() > 10000 {
+                break;
+            }
+        }
+    }
+}
+
+#[test]
+pub fn test_french_equinox_rule() {
+    use french_calendar::LeapRule;
+
+    println!("Verifying the equinoctial years from the year {} to {}", 1, 14);
+
+    for sdn in 2375840..=2380952 {
+        let f_date = french_calendar::sdn_to_french_with_rule(sdn, LeapRule::Equinox);
+        assert!(f_date == french_calendar::sdn_to_french(sdn), "{} {:?}", sdn, f_date);
+    }
+
+    /* Years 15 and 20 are sextile, years 16 to 19 are not. */
+    let sextiles: Vec<i32> = (1..=24).filter(|&y| LeapRule::Equinox.is_sextile(y)).collect();
+    assert!(sextiles == [3, 7, 11, 15, 20, 24], "{:?}", sextiles);
+
+    /* 1 Vendémiaire CCXXXII is 23 September 2023. */
+    let f_date = french_calendar::FrenchDate::new(232, 1, 1).unwrap();
+    let g_date = gregorian_calendar::GregorianDate::new(2023, 9, 23).unwrap();
+    assert!(french_calendar::french_to_sdn_with_rule(&f_date, LeapRule::Equinox)
+        == gregorian_calendar::gregorian_to_sdn(&g_date));
+
+    println!("Verifying all French republican calendar dates from the year {} to {} (Equinox)", 1, 1208);
+
+    let mut sdn = 2375840;
+    let mut f_date2 = french_calendar::FrenchDate::new(1, 1, 1).unwrap();
+
+    loop {
+        let sdn2 = french_calendar::french_to_sdn_with_rule(&f_date2, LeapRule::Equinox);
+        let f_date = french_calendar::sdn_to_french_with_rule(sdn, LeapRule::Equinox);
+        assert!(sdn2 == sdn && f_date == f_date2, "{} {:?}   erroneous: {} {:?}", sdn, f_date, sdn2, f_date2);
+
+        sdn += 1;
+        f_date2.next_day_with_rule(LeapRule::Equinox);
+
+        if f_date2.year() > 1208 {
+            break;
+        }
+    }
+
+    let f_date = french_calendar::FrenchDate::new(1209, 1, 1).unwrap();
+    assert!(french_calendar::french_to_sdn_with_rule(&f_date, LeapRule::Equinox) == 0);
+    assert!(french_calendar::sdn_to_french_with_rule(sdn, LeapRule::Equinox).year() == 0);
+}
+
+#[test]
+pub fn test_french_errors() {
+    use french_calendar::LeapRule;
+    use french_republican_calendar::CalendarError;
+
+    let check = |year, month, day, error| {
+        let f_date = french_calendar::FrenchDate::new_with_rule(year, month, day, LeapRule::Romme);
+        assert!(f_date == Err(error), "{} {} {} {:?}", year, month, day, f_date);
+    };
+
+    println!("Verifying the rejection of invalid French republican dates");
+
+    check(0, 1, 1, CalendarError::YearZero);
+    check(-1, 1, 1, CalendarError::OutOfRange);
+    check(8, 0, 18, CalendarError::InvalidMonth);
+    check(8, 14, 1, CalendarError::InvalidMonth);
+    check(8, 2, 0, CalendarError::InvalidDay);
+    check(8, 2, 31, CalendarError::InvalidDay);
+    check(8, 13, 7, CalendarError::InvalidDay);
+    check(8, 13, 6, CalendarError::NoSixthSansculottide);
+
+    let f_date = french_calendar::FrenchDate::new(1209, 1, 1).unwrap();
+    assert!(f_date.is_valid() && !f_date.is_valid_with_rule(LeapRule::Equinox));
+    assert!(french_calendar::try_french_to_sdn(&f_date, LeapRule::Equinox) == Err(CalendarError::OutOfRange));
+
+    let f_date = french_calendar::FrenchDate::new_with_rule(19, 13, 6, LeapRule::Continuous).unwrap();
+    assert!(f_date.is_valid_with_rule(LeapRule::Continuous) && !f_date.is_valid());
+    assert!(french_calendar::try_french_to_sdn(&f_date, LeapRule::Romme) == Err(CalendarError::NoSixthSansculottide));
+    let sdn = |value| Sdn::new(value).unwrap();
+    assert!(french_calendar::try_sdn_to_french(sdn(2375839), LeapRule::Romme) == Err(CalendarError::OutOfRange));
+
+    let f_date = french_calendar::FrenchDate::new(7, 13, 6).unwrap();
+    let sdn = french_calendar::try_french_to_sdn(&f_date, LeapRule::Romme);
+    assert!(sdn == Sdn::new(2378396));
+    assert!(french_calendar::try_sdn_to_french(Sdn::new(2378396).unwrap(), LeapRule::Romme).as_ref() == Ok(&f_date));
+    assert!(f_date.year() == 7 && f_date.month() == 13 && f_date.day() == 6);
+
+    assert!(!french_calendar::sdn_to_french(2375839).is_valid());
+}
+
+#[test]
+pub fn test_french_republican_days() {
+    use french_calendar::{DayOfMonth, FrenchDate, RepublicanDay, RepublicanMonth, Sansculottide};
+    use french_republican_calendar::CalendarError;
+
+    println!("Verifying the months and complementary days of the year {}", 3);
+
+    let mut f_date = FrenchDate::new(3, 1, 1).unwrap();
+    let mut days = 0;
+    while f_date.year() == 3 {
+        let republican_day = f_date.republican_day().unwrap();
+        match republican_day {
+            RepublicanDay::Month(month, day) => {
+                assert!(month.number() == f_date.month() && day.get() == f_date.day() && (1..=30).contains(&day.get()));
+                assert!(f_date.republican_month() == Some(month) && f_date.sansculottide().is_none());
+            }
+            RepublicanDay::Sansculottide(sansculottide) => {
+                assert!(f_date.month() == 13 && sansculottide.number() == f_date.day());
+                assert!(f_date.sansculottide() == Some(sansculottide) && f_date.republican_month().is_none());
+            }
+        }
+        let f_date2 = FrenchDate::from_republican_day(3, republican_day).unwrap();
+        assert!(f_date2 == f_date);
+        days += 1;
+        f_date.next_day();
+    }
+    assert!(days == 366);
+
+    let f_date = FrenchDate::new(8, 2, 18).unwrap();
+    assert!(f_date.republican_day() == RepublicanDay::month(RepublicanMonth::Brumaire, 18).ok());
+    assert!(RepublicanMonth::Brumaire.name() == "Brumaire" && RepublicanMonth::from_number(13).is_none());
+
+    let f_date = FrenchDate::from_republican_day(2, RepublicanDay::Sansculottide(Sansculottide::Recompenses)).unwrap();
+    assert!(f_date.month() == 13 && f_date.day() == 5);
+    assert!(Sansculottide::Revolution.name() == "Révolution" && Sansculottide::from_number(7).is_none());
+    assert!(FrenchDate::from_republican_day(2, RepublicanDay::Sansculottide(Sansculottide::Revolution)).is_err());
+    assert!(RepublicanDay::month(RepublicanMonth::Nivose, 31) == Err(CalendarError::InvalidDay));
+    assert!(DayOfMonth::new(0) == Err(CalendarError::InvalidDay));
+    assert!(DayOfMonth::new(30).map(DayOfMonth::get) == Ok(30));
+
+    let last = RepublicanDay::month(RepublicanMonth::Fructidor, 30).unwrap();
+    assert!(last < RepublicanDay::Sansculottide(Sansculottide::Vertu));
+
+    println!("Verifying the zero date of an invalid SDN");
+
+    let f_date = french_calendar::sdn_to_french(0);
+    assert!(f_date.republican_day().is_none() && f_date.month() == 0 && f_date.day() == 0);
+    assert!(f_date.republican_month().is_none() && f_date.sansculottide().is_none());
+    assert!(f_date.day_of_year() == 0 && f_date.day_name().is_empty() && !f_date.is_valid());
+}
+
+#[test]
+pub fn test_french_decades() {
+    use french_calendar::{FrenchDate, DECADE_DAY_NAME};
+
+    println!("Verifying the decades of the year {}", 2);
+
+    let mut f_date = FrenchDate::new(2, 1, 1).unwrap();
+    let mut day = 0;
+    while f_date.month() < 13 {
+        assert!(f_date.decade_of_year() == Some(day / 10 + 1), "{:?}", f_date);
+        assert!(f_date.decade() == Some(day / 10 % 3 + 1), "{:?}", f_date);
+        assert!(f_date.day_of_decade() == Some(day % 10 + 1), "{:?}", f_date);
+        day += 1;
+        f_date.next_day();
+    }
+    assert!(day == 360 && f_date.decade().is_none() && f_date.day_of_decade().is_none());
+
+    /* Décadi 20 Brumaire an II */
+    let f_date = FrenchDate::new(2, 2, 20).unwrap();
+    assert!(f_date.decade() == Some(2) && f_date.decade_of_year() == Some(5));
+    assert!(DECADE_DAY_NAME[f_date.day_of_decade().unwrap() as usize] == "Décadi");
+}
+
+#[test]
+pub fn test_french_rural_names() {
+    use french_calendar::{find_day_by_name, FrenchDate, RepublicanDay, RepublicanMonth, Sansculottide};
+
+    println!("Verifying the names of all the days of the year {}", 3);
+
+    let mut f_date = FrenchDate::new(3, 1, 1).unwrap();
+    while f_date.year() == 3 {
+        let name = f_date.day_name();
+        assert!(find_day_by_name(name) == f_date.republican_day(), "{:?} {}", f_date, name);
+        f_date.next_day();
+    }
+
+    assert!(FrenchDate::new(8, 2, 18).unwrap().day_name() == "Dentelaire");
+    assert!(FrenchDate::new(3, 13, 6).unwrap().day_name() == "Jour de la Révolution");
+    assert!(FrenchDate::new(3, 1, 7).unwrap().day_of_year() == 7);
+    assert!(FrenchDate::new(3, 13, 6).unwrap().day_of_year() == 366);
+
+    assert!(find_day_by_name("Carotte") == RepublicanDay::month(RepublicanMonth::Vendemiaire, 7).ok());
+    assert!(find_day_by_name(" CAROTTE ") == RepublicanDay::month(RepublicanMonth::Vendemiaire, 7).ok());
+    assert!(find_day_by_name("erable a sucre") == RepublicanDay::month(RepublicanMonth::Frimaire, 21).ok());
+    assert!(find_day_by_name("OEILLET") == RepublicanDay::month(RepublicanMonth::Prairial, 16).ok());
+    assert!(find_day_by_name("baton d’or") == RepublicanDay::month(RepublicanMonth::Floreal, 13).ok());
+    assert!(find_day_by_name("genie") == Some(RepublicanDay::Sansculottide(Sansculottide::Genie)));
+    assert!(find_day_by_name("Jour de l'opinion") == Some(RepublicanDay::Sansculottide(Sansculottide::Opinion)));
+    assert!(find_day_by_name("Carottes").is_none());
+}
@@ -1,4 +1,4 @@
-use repub_cli::gregorian_calendar;
+use french_republican_calendar::{gregorian_calendar, Sdn};
 
 /* $selId: vgregor.c,v 2.0 1995/10/24 01:13:06 lees Exp $
  * Copyright 1993-1995, Scott E. Lee, all rights reserved.
@@ -12,7 +12,7 @@ pub fn test_gregorian_dates() {
     println!("Verifying all Gregorian calendar dates from the year {} to {}", -4714, 10000);
 
     let mut sdn = 1;
-    let mut g_date2 = gregorian_calendar::GregorianDate{day: 25, month: 11, year: -4714};
+    let mut g_date2 = gregorian_calendar::GregorianDate::new(-4714, 11, 25).unwrap();
 
     loop {
         let sdn2 = gregorian_calendar::gregorian_to_sdn(&g_date2);
@@ -21,8 +21,46 @@ pub fn test_gregorian_dates() {
 
         sdn += 1;
         g_date2.next_day();
-        if g_date2.year > 10000 {
+        if g_date2.year() > 10000 {
             break;
         }
     }
 }
+
+#[test]
+pub fn test_gregorian_errors() {
+    use french_republican_calendar::CalendarError;
+
+    let check = |year, month, day, error| {
+        let g_date = gregorian_calendar::GregorianDate::new(year, month, day);
+        assert!(g_date == Err(error), "{} {} {} {:?}", year, month, day, g_date);
+    };
+
+    println!("Verifying the rejection of invalid Gregorian dates");
+
+    check(0, 1, 1, CalendarError::YearZero);
+    check(-1000001, 12, 31, CalendarError::OutOfRange);
+    check(1000001, 1, 1, CalendarError::OutOfRange);
+    check(1799, 0, 1, CalendarError::InvalidMonth);
+    check(1799, 13, 1, CalendarError::InvalidMonth);
+    check(1799, 11, 0, CalendarError::InvalidDay);
+    check(1799, 11, 31, CalendarError::InvalidDay);
+    check(1900, 2, 29, CalendarError::InvalidDay);
+
+    let g_date = gregorian_calendar::GregorianDate::new(2000, 2, 29).unwrap();
+    assert!(g_date.is_valid());
+    let sdn = |value| Sdn::new(value).unwrap();
+    assert!(gregorian_calendar::try_gregorian_to_sdn(&g_date) == Ok(sdn(2451604)));
+    assert!(gregorian_calendar::try_sdn_to_gregorian(sdn(2451604)).as_ref() == Ok(&g_date));
+    assert!(gregorian_calendar::try_sdn_to_gregorian(sdn(-363521075)) == Err(CalendarError::OutOfRange));
+    assert!(gregorian_calendar::try_sdn_to_gregorian(sdn(366963926)) == Err(CalendarError::OutOfRange));
+    assert!(gregorian_calendar::try_sdn_to_gregorian(sdn(366963925)).map(|g_date| g_date.year()) == Ok(1000000));
+    assert!(g_date.year() == 2000 && g_date.month() == 2 && g_date.day() == 29);
+
+    assert!(!gregorian_calendar::sdn_to_gregorian(0).is_valid());
+
+    /* The legacy routines reject the dates before SDN 1. */
+    let g_date = gregorian_calendar::GregorianDate::new(-4714, 11, 24).unwrap();
+    assert!(gregorian_calendar::try_gregorian_to_sdn(&g_date) == Ok(sdn(0)));
+    assert!(gregorian_calendar::gregorian_to_sdn(&g_date) == 0);
+}
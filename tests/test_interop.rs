@@ -0,0 +1,71 @@
+#![cfg(any(feature = "chrono", feature = "time"))]
+
+use french_republican_calendar::{
+    french_calendar::FrenchDate, gregorian_calendar::GregorianDate, jewish_calendar::JewishDate,
+    julian_calendar::JulianDate, CalendarError,
+};
+
+#[cfg(feature = "chrono")]
+#[test]
+pub fn test_chrono() {
+    use chrono::NaiveDate;
+
+    println!("Verifying the conversions to and from chrono::NaiveDate");
+
+    let naive_date = NaiveDate::from_ymd_opt(1799, 11, 9).unwrap();
+    let f_date = FrenchDate::try_from(naive_date).unwrap();
+    assert!(f_date == FrenchDate::new(8, 2, 18).unwrap(), "{:?}", f_date);
+    assert!(NaiveDate::try_from(f_date) == Ok(naive_date));
+    assert!(GregorianDate::try_from(naive_date) == GregorianDate::new(1799, 11, 9));
+    assert!(JulianDate::try_from(naive_date) == JulianDate::new(1799, 10, 29));
+    assert!(JewishDate::try_from(naive_date) == JewishDate::new(5560, 2, 11));
+
+    /* chrono counts the years B.C. astronomically: year 0 is 1 B.C. */
+    let naive_date = NaiveDate::from_ymd_opt(0, 12, 31).unwrap();
+    let g_date = GregorianDate::try_from(naive_date).unwrap();
+    assert!(g_date == GregorianDate::new(-1, 12, 31).unwrap(), "{:?}", g_date);
+    assert!(NaiveDate::try_from(g_date) == Ok(naive_date));
+
+    println!("Verifying the dates out of range");
+
+    let naive_date = NaiveDate::from_ymd_opt(1700, 1, 1).unwrap();
+    assert!(FrenchDate::try_from(naive_date) == Err(CalendarError::OutOfRange));
+    let naive_date = NaiveDate::from_ymd_opt(-5000, 1, 1).unwrap();
+    assert!(GregorianDate::try_from(naive_date) == GregorianDate::new(-5001, 1, 1));
+    assert!(JulianDate::try_from(NaiveDate::MIN).is_ok());
+    let naive_date = NaiveDate::from_ymd_opt(-3761, 1, 1).unwrap();
+    assert!(JewishDate::try_from(naive_date) == Err(CalendarError::OutOfRange));
+    assert!(JewishDate::try_from(NaiveDate::MIN) == Err(CalendarError::OutOfRange));
+    let g_date = GregorianDate::new(300000, 1, 1).unwrap();
+    assert!(NaiveDate::try_from(g_date) == Err(CalendarError::OutOfRange));
+    assert!(GregorianDate::try_from(NaiveDate::MAX).is_ok());
+}
+
+#[cfg(feature = "time")]
+#[test]
+pub fn test_time() {
+    use time::{Date, Month};
+
+    println!("Verifying the conversions to and from time::Date");
+
+    let date = Date::from_calendar_date(1799, Month::November, 9).unwrap();
+    let f_date = FrenchDate::try_from(date).unwrap();
+    assert!(f_date == FrenchDate::new(8, 2, 18).unwrap(), "{:?}", f_date);
+    assert!(Date::try_from(f_date) == Ok(date));
+    assert!(GregorianDate::try_from(date) == GregorianDate::new(1799, 11, 9));
+    assert!(JulianDate::try_from(date) == JulianDate::new(1799, 10, 29));
+    assert!(JewishDate::try_from(date) == JewishDate::new(5560, 2, 11));
+
+    let f_date = FrenchDate::new(3, 13, 6).unwrap();
+    let date = Date::try_from(f_date).unwrap();
+    assert!(date == Date::from_calendar_date(1795, Month::September, 22).unwrap(), "{}", date);
+
+    println!("Verifying the dates out of range");
+
+    let date = Date::from_calendar_date(1700, Month::January, 1).unwrap();
+    assert!(FrenchDate::try_from(date) == Err(CalendarError::OutOfRange));
+    let g_date = GregorianDate::new(10000, 1, 1).unwrap();
+    assert!(Date::try_from(g_date) == Err(CalendarError::OutOfRange));
+    let g_date = GregorianDate::new(-1, 12, 31).unwrap();
+    assert!(Date::try_from(g_date) == Date::from_calendar_date(0, Month::December, 31).map_err(|_| CalendarError::OutOfRange));
+}
@@ -0,0 +1,85 @@
+use french_republican_calendar::{gregorian_calendar, jewish_calendar, Sdn};
+
+/* $selId: vjewish.c,v 2.0 1995/10/24 01:13:06 lees Exp $
+ * Copyright 1993-1995, Scott E. Lee, all rights reserved.
+ * Permission granted to use, copy, modify, distribute and sell so long as
+ * the above copyright and this permission statement are retained in all
+ * copies.  THERE IS NO WARRANTY - USE AT YOUR OWN RISK.
+ */
+
+#[test]
+pub fn test_jewish_dates() {
+    println!("Verifying all Jewish calendar dates from the year {} to {}", 1, 10000);
+
+    let mut sdn = 347998;
+    let mut j_date2 = jewish_calendar::JewishDate::new(1, 1, 1).unwrap();
+
+    loop {
+        let sdn2 = jewish_calendar::jewish_to_sdn(&j_date2);
+        let j_date = jewish_calendar::sdn_to_jewish(sdn);
+        assert!(sdn2 == sdn && j_date == j_date2, "{} {:?}   erroneous: {} {:?}", sdn, j_date, sdn2, j_date2);
+
+        sdn += 1;
+        j_date2.next_day();
+        if j_date2.year() > 10000 {
+            break;
+        }
+    }
+}
+
+#[test]
+pub fn test_jewish_years() {
+    use french_republican_calendar::CalendarError;
+    use jewish_calendar::YearKind;
+
+    println!("Verifying the kinds of the Jewish years");
+
+    /* Rosh Ha-Shanah 5784 was on 16 September 2023, 5785 on 3 October 2024. */
+    let g_date = gregorian_calendar::GregorianDate::new(2023, 9, 16).unwrap();
+    let j_date = jewish_calendar::JewishDate::new(5784, 1, 1).unwrap();
+    assert!(jewish_calendar::jewish_to_sdn(&j_date) == gregorian_calendar::gregorian_to_sdn(&g_date));
+    let g_date = gregorian_calendar::GregorianDate::new(2024, 10, 3).unwrap();
+    let j_date = jewish_calendar::JewishDate::new(5785, 1, 1).unwrap();
+    assert!(jewish_calendar::jewish_to_sdn(&j_date) == gregorian_calendar::gregorian_to_sdn(&g_date));
+
+    assert!(jewish_calendar::is_leap_year(5784));
+    assert!(!jewish_calendar::is_leap_year(5785));
+    assert!(jewish_calendar::year_length(5784) == 383);
+    assert!(jewish_calendar::year_kind(5784) == YearKind::Deficient);
+    assert!(jewish_calendar::year_length(5785) == 355);
+    assert!(jewish_calendar::year_kind(5785) == YearKind::Complete);
+    assert!(jewish_calendar::year_kind(5782) == YearKind::Regular);
+
+    let mut count = [0; 3];
+    for year in 1..=10000 {
+        let length = jewish_calendar::year_length(year);
+        let expected: &[i32] = if jewish_calendar::is_leap_year(year) {
+            &[383, 384, 385]
+        } else {
+            &[353, 354, 355]
+        };
+        assert!(expected.contains(&length), "{} {}", year, length);
+        count[jewish_calendar::year_kind(year) as usize] += 1;
+    }
+    assert!(count.iter().all(|&c| c > 0));
+
+    println!("Verifying the Jewish month names");
+
+    assert!(jewish_calendar::month_name(5784, 6) == "Adar I");
+    assert!(jewish_calendar::month_name(5784, 7) == "Adar II");
+    assert!(jewish_calendar::month_name(5785, 6) == "Adar");
+    assert!(jewish_calendar::month_name(5785, 7).is_empty());
+    assert!(jewish_calendar::month_name(5785, 13) == "Elul");
+
+    println!("Verifying the rejection of invalid Jewish dates");
+
+    assert!(jewish_calendar::JewishDate::new(0, 1, 1) == Err(CalendarError::OutOfRange));
+    assert!(jewish_calendar::JewishDate::new(5785, 7, 1) == Err(CalendarError::InvalidMonth));
+    assert!(jewish_calendar::JewishDate::new(5785, 14, 1) == Err(CalendarError::InvalidMonth));
+    assert!(jewish_calendar::JewishDate::new(5785, 6, 30) == Err(CalendarError::InvalidDay));
+    assert!(jewish_calendar::JewishDate::new(5784, 6, 30).is_ok());
+    assert!(jewish_calendar::JewishDate::new(5784, 2, 30) == Err(CalendarError::InvalidDay));
+    assert!(jewish_calendar::JewishDate::new(5785, 3, 30).is_ok());
+    assert!(jewish_calendar::try_sdn_to_jewish(Sdn::new(347997).unwrap()) == Err(CalendarError::OutOfRange));
+    assert!(!jewish_calendar::sdn_to_jewish(0).is_valid());
+}
@@ -0,0 +1,42 @@
+use french_republican_calendar::{gregorian_calendar, julian_calendar};
+
+/* $selId: vjulian.c,v 2.0 1995/10/24 01:13:06 lees Exp $
+ * Copyright 1993-1995, Scott E. Lee, all rights reserved.
+ * Permission granted to use, copy, modify, distribute and sell so long as
+ * the above copyright and this permission statement are retained in all
+ * copies.  THERE IS NO WARRANTY - USE AT YOUR OWN RISK.
+ */
+
+#[test]
+pub fn test_julian_dates() {
+    println!("Verifying all Julian calendar dates from the year {} to {}", -4713, 10000);
+
+    let mut sdn = 1;
+    let mut j_date2 = julian_calendar::JulianDate::new(-4713, 1, 2).unwrap();
+
+    loop {
+        let sdn2 = julian_calendar::julian_to_sdn(&j_date2);
+        let j_date = julian_calendar::sdn_to_julian(sdn);
+        assert!(sdn2 == sdn && j_date == j_date2, "{} {:?}   erroneous: {} {:?}", sdn, j_date, sdn2, j_date2);
+
+        sdn += 1;
+        j_date2.next_day();
+        if j_date2.year() > 10000 {
+            break;
+        }
+    }
+
+    println!("Verifying the adoption of the Gregorian calendar");
+
+    /* Thursday 4 October 1582 was followed by Friday 15 October 1582. */
+    let j_date = julian_calendar::JulianDate::new(1582, 10, 4).unwrap();
+    let g_date = gregorian_calendar::GregorianDate::new(1582, 10, 15).unwrap();
+    let sdn = julian_calendar::julian_to_sdn(&j_date);
+    assert!(sdn + 1 == gregorian_calendar::gregorian_to_sdn(&g_date));
+    assert!(gregorian_calendar::day_of_week(sdn) == 4);
+
+    assert!(julian_calendar::JulianDate::new(1900, 2, 29).is_ok());
+    assert!(julian_calendar::JulianDate::new(1901, 2, 29).is_err());
+    assert!(julian_calendar::JulianDate::new(-1000001, 12, 31).is_err());
+    assert!(julian_calendar::julian_to_sdn(&julian_calendar::JulianDate::new(-4713, 1, 1).unwrap()) == 0);
+}
@@ -0,0 +1,81 @@
+use french_republican_calendar::{french_date, gregorian_date};
+use french_republican_calendar::french_calendar::{french_to_sdn, sdn_to_french, FrenchDate};
+use french_republican_calendar::gregorian_calendar::{gregorian_to_sdn, sdn_to_gregorian, GregorianDate};
+use french_republican_calendar::julian_calendar::{julian_to_sdn, JulianDate};
+use french_republican_calendar::literal::{parse_french, parse_gregorian, LiteralError};
+use french_republican_calendar::CalendarError;
+
+const COUP_D_ETAT: FrenchDate = french_date!("18 Brumaire VIII");
+const SAME_DAY: GregorianDate = gregorian_date!("1799-11-09");
+const THERMIDOR: FrenchDate = french_date!("9 thermidor an II");
+const FALL_OF_ROBESPIERRE: GregorianDate = gregorian_date!("1794-07-27");
+const SDN: i32 = french_to_sdn(&COUP_D_ETAT);
+const IDES_OF_MARCH: i32 = match JulianDate::new(-44, 3, 15) {
+    Ok(julian_date) => julian_to_sdn(&julian_date),
+    Err(_) => panic!("invalid Julian date"),
+};
+
+#[test]
+pub fn test_date_literals() {
+    println!("Verifying the date literals checked at compile time");
+
+    assert!(SDN == gregorian_to_sdn(&SAME_DAY), "18 Brumaire VIII   erroneous SDN: {}", SDN);
+    assert!(sdn_to_gregorian(SDN) == SAME_DAY);
+    assert!(sdn_to_french(gregorian_to_sdn(&FALL_OF_ROBESPIERRE)) == THERMIDOR);
+    assert!((COUP_D_ETAT.year(), COUP_D_ETAT.month(), COUP_D_ETAT.day()) == (8, 2, 18));
+
+    assert!(french_date!("6 Extra III") == FrenchDate::new(3, 13, 6).unwrap());
+    assert!(french_date!("5 complémentaire an XI") == FrenchDate::new(11, 13, 5).unwrap());
+    assert!(french_date!("1 VENDÉMIAIRE an I") == FrenchDate::new(1, 1, 1).unwrap());
+    assert!(french_date!("1 vendemiaire 1") == FrenchDate::new(1, 1, 1).unwrap());
+    assert!(french_date!("3 Sansculottides, an IIII") == FrenchDate::new(4, 13, 3).unwrap());
+    assert!(french_date!("1 germinal an lxxix") == FrenchDate::new(79, 7, 1).unwrap());
+    assert!(french_date!("1 vendémiaire an CCXXXV") == FrenchDate::new(235, 1, 1).unwrap());
+
+    assert!(gregorian_date!("-0043-03-15") == GregorianDate::new(-44, 3, 15).unwrap());
+    assert!(gregorian_date!("+12345-06-07") == GregorianDate::new(12345, 6, 7).unwrap());
+    assert!(IDES_OF_MARCH == gregorian_to_sdn(&GregorianDate::new(-44, 3, 13).unwrap()));
+}
+
+#[test]
+pub fn test_literal_errors() {
+    println!("Verifying the errors of the const parsers");
+
+    let check_french = |text, expected: LiteralError| {
+        let result = parse_french(text);
+        assert!(result == Err(expected), "{:?}   erroneous: {:?}", text, result);
+    };
+    check_french("", LiteralError::InvalidDay);
+    check_french("dix-huit brumaire VIII", LiteralError::InvalidDay);
+    check_french("18 brum VIII", LiteralError::UnknownMonth);
+    check_french("18 brumaire", LiteralError::MissingYear);
+    check_french("18 brumaire an", LiteralError::MissingYear);
+    check_french("18 brumaire VIIIZ", LiteralError::InvalidYear);
+    check_french("1 vendemiaire mix", LiteralError::InvalidYear);
+    check_french("1 vendemiaire dix", LiteralError::InvalidYear);
+    check_french("1 vendemiaire IIIIIIII", LiteralError::InvalidYear);
+    check_french("1 vendemiaire IIX", LiteralError::InvalidYear);
+    check_french("1 vendemiaire an MMMM", LiteralError::InvalidYear);
+    check_french("18 brumaire VIII 1799", LiteralError::UnexpectedWord);
+    check_french("31 brumaire VIII", LiteralError::Date(CalendarError::InvalidDay));
+    check_french("6 Extra II", LiteralError::Date(CalendarError::NoSixthSansculottide));
+    check_french("1 nivôse 0", LiteralError::Date(CalendarError::YearZero));
+
+    let check_gregorian = |text, expected: LiteralError| {
+        let result = parse_gregorian(text);
+        assert!(result == Err(expected), "{:?}   erroneous: {:?}", text, result);
+    };
+    check_gregorian("1799-11", LiteralError::Malformed);
+    check_gregorian("99-11-09", LiteralError::Malformed);
+    check_gregorian("1799-11-09x", LiteralError::Malformed);
+    check_gregorian("1799/11/09", LiteralError::Malformed);
+    check_gregorian("1900-02-29", LiteralError::Date(CalendarError::InvalidDay));
+    check_gregorian("1799-13-01", LiteralError::Date(CalendarError::InvalidMonth));
+    check_gregorian("+1000001-01-01", LiteralError::Date(CalendarError::OutOfRange));
+    check_gregorian("99999999999-01-01", LiteralError::Date(CalendarError::OutOfRange));
+    check_gregorian("-99999999999-01-01", LiteralError::Date(CalendarError::OutOfRange));
+    check_gregorian("1799-99999999999-09", LiteralError::Date(CalendarError::InvalidMonth));
+
+    assert!(LiteralError::Malformed.to_string() == "malformed date, expected YYYY-MM-DD");
+    assert!(LiteralError::Date(CalendarError::InvalidDay).to_string() == CalendarError::InvalidDay.to_string());
+}
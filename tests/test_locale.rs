@@ -0,0 +1,94 @@
+use french_republican_calendar::{
+    french_calendar, gregorian_calendar,
+    locale::Locale,
+};
+
+#[test]
+pub fn test_locale_names() {
+    println!("Verifying the names in every locale");
+
+    for month in 0..=13 {
+        assert!(Locale::French.republican_month_name(month) == french_calendar::FRENCH_MONTH_NAME[month as usize]);
+    }
+    for day_of_year in 0..=366 {
+        assert!(Locale::French.rural_day_name(day_of_year) == french_calendar::RURAL_DAY_NAME[day_of_year as usize]);
+    }
+    for month in 0..=12 {
+        assert!(Locale::English.gregorian_month_name(month) == gregorian_calendar::LONG_MONTH_NAME[month as usize]);
+    }
+    for day_of_week in 0..7 {
+        assert!(Locale::English.day_of_week_name(day_of_week) == gregorian_calendar::LONG_DAY_NAME[day_of_week as usize]);
+    }
+
+    for locale in [Locale::French, Locale::English, Locale::BritishSatirical] {
+        for month in 1..=13 {
+            assert!(!locale.republican_month_name(month).is_empty(), "{:?} {}", locale, month);
+        }
+        for day_of_year in 1..=366 {
+            assert!(!locale.rural_day_name(day_of_year).is_empty(), "{:?} {}", locale, day_of_year);
+        }
+        for month in 1..=12 {
+            assert!(!locale.gregorian_month_name(month).is_empty(), "{:?} {}", locale, month);
+            assert!(!locale.short_gregorian_month_name(month).is_empty(), "{:?} {}", locale, month);
+        }
+        for day in 1..=10 {
+            assert!(!locale.decade_day_name(day).is_empty(), "{:?} {}", locale, day);
+            assert!(!locale.short_decade_day_name(day).is_empty(), "{:?} {}", locale, day);
+        }
+        assert!(locale.republican_month_name(0).is_empty() && locale.republican_month_name(14).is_empty());
+        assert!(locale.day_of_week_name(-1).is_empty() && locale.day_of_week_name(7).is_empty());
+    }
+
+    assert!(Locale::English.republican_month_name(1) == "Vintage");
+    assert!(Locale::English.republican_month_name(2) == "Mist");
+    assert!(Locale::English.republican_month_name(3) == "Frost");
+    assert!(Locale::BritishSatirical.republican_month_name(1) == "Wheezy");
+    assert!(Locale::BritishSatirical.republican_month_name(2) == "Sneezy");
+    assert!(Locale::BritishSatirical.republican_month_name(3) == "Freezy");
+    assert!(Locale::French.gregorian_month_name(8) == "août");
+    assert!(Locale::French.day_of_week_name(0) == "dimanche");
+
+    println!("Verifying the fallback of missing names");
+
+    for day in 1..=10 {
+        assert!(Locale::English.decade_day_name(day) != Locale::French.decade_day_name(day));
+    }
+    assert!(Locale::English.rural_day_name(31) == "Apple");
+    assert!(Locale::English.rural_day_name(181) == "Primrose");
+    assert!(Locale::English.rural_day_name(300) == "Shawm");
+    assert!(Locale::English.rural_day_name(335) == "Salmon");
+
+    assert!(Locale::BritishSatirical.fallback() == Some(Locale::English));
+    assert!(Locale::English.fallback() == Some(Locale::French));
+    assert!(Locale::French.fallback().is_none());
+    assert!(Locale::BritishSatirical.republican_month_name(13) == "Complementary Days");
+    assert!(Locale::BritishSatirical.gregorian_month_name(1) == "January");
+    assert!(Locale::English.decade_day_name(10) == "Tenth day");
+    assert!(Locale::BritishSatirical.short_decade_day_name(1) == "1st");
+    assert!(Locale::English.rural_day_name(1) == "Grape");
+    assert!(Locale::BritishSatirical.rural_day_name(360) == "Basket");
+    assert!(Locale::English.rural_day_name(366) == "Celebration of the Revolution");
+    assert!(Locale::BritishSatirical.sansculottide_name(2) == "Genius");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+pub fn test_locale_format() {
+    use french_republican_calendar::{french_calendar::FrenchDate, gregorian_calendar::GregorianDate};
+
+    println!("Verifying the formatting in every locale");
+
+    let f_date = FrenchDate::new(8, 2, 18).unwrap();
+    assert!(f_date.format("%A %d %B").with_locale(Locale::French).to_string() == "Octidi 18 Brumaire");
+    assert!(f_date.format("%d %B").with_locale(Locale::English).to_string() == "18 Mist");
+    assert!(f_date.format("%d %B").with_locale(Locale::BritishSatirical).to_string() == "18 Sneezy");
+
+    let f_date = FrenchDate::new(3, 13, 1).unwrap();
+    assert!(f_date.format("%A, %S").with_locale(Locale::English).to_string() == "Celebration of Virtue, Virtue");
+
+    let g_date = GregorianDate::new(1799, 11, 9).unwrap();
+    assert!(g_date.format("%A %-d %B %Y").with_locale(Locale::French).to_string() == "samedi 9 novembre 1799");
+    assert!(g_date.format("%a %-d %b").with_locale(Locale::French).to_string() == "sam. 9 nov.");
+    assert!(g_date.format("%A %-d %B").with_locale(Locale::BritishSatirical).to_string() == "Saturday 9 November");
+    assert!(g_date.format("%A %-d %B").to_string() == "Saturday 9 November");
+}
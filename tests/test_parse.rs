@@ -0,0 +1,131 @@
+#![cfg(feature = "alloc")]
+
+use french_republican_calendar::{
+    french_calendar::{FrenchDate, LeapRule},
+    parse::{self, ParseErrorKind},
+    roman, CalendarError,
+};
+
+#[test]
+pub fn test_parse_republican() {
+    println!("Verifying the parsing of French republican dates");
+
+    let check = |text: &str, year, month, day| {
+        let f_date = text.parse::<FrenchDate>().ok();
+        assert!(f_date == FrenchDate::new(year, month, day).ok(), "{:?}   erroneous: {:?}", text, f_date);
+    };
+
+    check("18 brumaire an VIII", 8, 2, 18);
+    check("le 9 thermidor de l'an 2", 2, 11, 9);
+    check("1er vendémiaire an III", 3, 1, 1);
+    check("5e jour complémentaire an XI", 11, 13, 5);
+    check("  Le 14 FRIMAIRE, An ii.", 2, 3, 14);
+    check("14 nivose an 4", 4, 4, 14);
+    check("2ème Pluviôse de l’an IV", 4, 5, 2);
+    check("30 fruct. an XII", 12, 12, 30);
+    check("premier germinal an X", 10, 7, 1);
+    check("3 therm an 2", 2, 11, 3);
+    check("6e jour des sans-culottides de l'an III", 3, 13, 6);
+    check("premier jour des sansculottides de l'an IV", 4, 13, 1);
+    check("2 sansculottide an 5", 5, 13, 2);
+    check("jour de la Révolution an III", 3, 13, 6);
+    check("Fête du Génie an VII", 7, 13, 2);
+    check("fete des recompenses an 1", 1, 13, 5);
+    check("opinion an IIII", 4, 13, 4);
+    check("3 pluviôse an xiiii", 14, 5, 3);
+    check("1er germinal an LXXIX", 79, 7, 1);
+    check("1er germinal an xcix", 99, 7, 1);
+    check("12 messidor 1234", 1234, 10, 12);
+    check("Octidi 18 Brumaire an VIII", 8, 2, 18);
+    check("le décadi 20 brumaire an II", 2, 2, 20);
+    check("Primidi 18 brumaire VIII", 8, 2, 18);
+    check("1er vendémiaire an CCXXXV", 235, 1, 1);
+    check("1er vendémiaire an MDCCXCIIII", 1794, 1, 1);
+    check("18 brumaire an mix", 1009, 2, 18);
+    check("18 brumaire an C", 100, 2, 18);
+
+    assert!(parse::parse_republican("15 floréal an 20").is_ok());
+    assert!(parse::parse_republican_with_rule("6e jour complémentaire an 20", LeapRule::Romme).is_ok());
+    assert!(parse::parse_republican_with_rule("6e jour complémentaire an 20", LeapRule::Continuous).is_err());
+}
+
+#[test]
+pub fn test_parse_errors() {
+    println!("Verifying the errors of the parsing of French republican dates");
+
+    let check = |text: &str, kind, token: &str| {
+        let error = text.parse::<FrenchDate>().unwrap_err();
+        assert!(error.kind() == kind && &text[error.span()] == token, "{:?}   erroneous: {:?}", text, error);
+    };
+
+    check("", ParseErrorKind::Empty, "");
+    check(" , ", ParseErrorKind::Empty, "");
+    check("dix-huit brumaire an VIII", ParseErrorKind::InvalidDay, "dix");
+    check("18th brumaire an VIII", ParseErrorKind::InvalidDay, "18th");
+    check("18 brumiare an VIII", ParseErrorKind::UnknownMonth, "brumiare");
+    check("18 fr an VIII", ParseErrorKind::UnknownMonth, "fr");
+    check("18 jour an VIII", ParseErrorKind::UnknownMonth, "jour");
+    check("18 brumaire", ParseErrorKind::MissingYear, "");
+    check("18 brumaire an", ParseErrorKind::MissingYear, "");
+    check("18 brumaire an huit", ParseErrorKind::InvalidYear, "huit");
+    check("18 brumaire mix", ParseErrorKind::InvalidYear, "mix");
+    check("18 brumaire civil", ParseErrorKind::InvalidYear, "civil");
+    check("18 brumaire an civil", ParseErrorKind::InvalidYear, "civil");
+    check("18 brumaire an MMMM", ParseErrorKind::InvalidYear, "MMMM");
+    check("18 brumaire an dim", ParseErrorKind::InvalidYear, "dim");
+    check("18 brumaire an IIIIIIII", ParseErrorKind::InvalidYear, "IIIIIIII");
+    check("18 brumaire an VIIII", ParseErrorKind::InvalidYear, "VIIII");
+    check("18 brumaire an IIX", ParseErrorKind::InvalidYear, "IIX");
+    check("18 brumaire dix", ParseErrorKind::InvalidYear, "dix");
+    check("18 brumaire C", ParseErrorKind::InvalidYear, "C");
+    check("Octidi brumaire an VIII", ParseErrorKind::InvalidDay, "brumaire");
+    check("18 brumaire an VIII de la République", ParseErrorKind::UnexpectedToken, "de");
+    check("31 brumaire an VIII", ParseErrorKind::Date(CalendarError::InvalidDay), "31");
+    check("6e jour complémentaire an II", ParseErrorKind::Date(CalendarError::NoSixthSansculottide), "6e");
+    check("jour de la révolution an II", ParseErrorKind::Date(CalendarError::NoSixthSansculottide), "révolution");
+    check("1 vendémiaire an 0", ParseErrorKind::Date(CalendarError::YearZero), "0");
+
+    let error = "18 brumaire".parse::<FrenchDate>().unwrap_err();
+    assert!(error.span() == (11..11));
+    assert!(error.to_string() == "missing year at 11");
+    let error = "31 brumaire an VIII".parse::<FrenchDate>().unwrap_err();
+    assert!(error.to_string() == "invalid day for this month at 0..2");
+}
+
+#[test]
+pub fn test_parse_roman_numerals() {
+    println!("Verifying the lenient parsing of Roman numerals");
+
+    assert!(roman::from_roman("VIII") == Some(8));
+    assert!(roman::from_roman("viii") == Some(8));
+    assert!(roman::from_roman("IIII") == Some(4));
+    assert!(roman::from_roman("IIX") == Some(8));
+    assert!(roman::from_roman("MDCCXCIX") == Some(1799));
+    assert!(roman::from_roman("").is_none());
+    assert!(roman::from_roman("VIIIe").is_none());
+}
+
+#[test]
+pub fn test_parse_display() {
+    println!("Verifying that the displayed French republican dates are parsed back");
+
+    let check = |f_date: FrenchDate| {
+        let text = f_date.to_string();
+        assert!(text.parse() == Ok(f_date), "{:?}   erroneous: {:?}", text, text.parse::<FrenchDate>());
+    };
+
+    for year in 1..=120 {
+        for day in 1..=366 {
+            if let Ok(f_date) = FrenchDate::new(year, (day - 1) / 30 + 1, (day - 1) % 30 + 1) {
+                check(f_date);
+            }
+        }
+    }
+    for year in (121..=4100).chain([9999, 123456, i32::MAX]) {
+        for (month, day) in [(1, 1), (2, 18), (7, 10), (12, 30), (13, 5), (13, 6)] {
+            if let Ok(f_date) = FrenchDate::new(year, month, day) {
+                check(f_date);
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use french_republican_calendar::{
+    french_calendar::{FrenchDate, LeapRule},
+    gregorian_calendar::GregorianDate,
+    jewish_calendar::JewishDate,
+    range::{DateRange, Interval, Step},
+    CalendarDate, CalendarError, ClampPolicy, Days,
+};
+
+#[test]
+pub fn test_date_ranges() {
+    println!("Verifying the ranges of dates");
+
+    let start = GregorianDate::new(2024, 1, 30).unwrap();
+    let end = GregorianDate::new(2024, 2, 2).unwrap();
+    let days: Vec<i32> = DateRange::inclusive(start, end).map(|g_date| g_date.day()).collect();
+    assert!(days == [30, 31, 1, 2]);
+    let days: Vec<i32> = DateRange::exclusive(start, end).map(|g_date| g_date.day()).collect();
+    assert!(days == [30, 31, 1]);
+    let days: Vec<i32> = DateRange::inclusive(start, end).rev().map(|g_date| g_date.day()).collect();
+    assert!(days == [2, 1, 31, 30]);
+    assert!(DateRange::inclusive(start, end).len() == 4);
+    assert!(DateRange::inclusive(end, start).next().is_none());
+    assert!(DateRange::exclusive(start, start).next().is_none());
+    assert!(DateRange::inclusive(start, start).count() == 1);
+
+    let mut range = DateRange::inclusive(start, end);
+    assert!(range.next() == Some(start) && range.next_back() == Some(end));
+    assert!(range.len() == 2);
+    assert!(range.nth(1).map(|g_date| g_date.day()) == Some(1) && range.next().is_none());
+
+    let days: Vec<i32> = DateRange::inclusive(start, end).by(Step::Days(2)).map(|g_date| g_date.day()).collect();
+    assert!(days == [30, 1]);
+
+    /* A date without a SDN under the default rule gives an empty range. */
+    let continuous = FrenchDate::new_with_rule(19, 13, 6, LeapRule::Continuous).unwrap();
+    assert!(DateRange::inclusive(continuous, FrenchDate::new(20, 1, 1).unwrap()).len() == 0);
+    assert!(DateRange::inclusive(FrenchDate::new(19, 1, 1).unwrap(), continuous).len() == 0);
+
+    println!("Verifying the ranges by month and by year");
+
+    let start = GregorianDate::new(2023, 1, 31).unwrap();
+    let end = GregorianDate::new(2023, 5, 31).unwrap();
+    let days: Vec<(i32, i32)> = DateRange::inclusive(start, end).by(Step::Months(1))
+        .map(|g_date| (g_date.month(), g_date.day())).collect();
+    assert!(days == [(1, 31), (2, 28), (3, 31), (4, 30), (5, 31)]);
+    let days: Vec<(i32, i32)> = DateRange::exclusive(start, end).by(Step::Months(2)).rev()
+        .map(|g_date| (g_date.month(), g_date.day())).collect();
+    assert!(days == [(3, 31), (1, 31)]);
+
+    let start = FrenchDate::new(3, 13, 6).unwrap();
+    let end = FrenchDate::new(11, 13, 6).unwrap();
+    let years: Vec<(i32, i32)> = DateRange::inclusive(start, end).by(Step::Years(1))
+        .map(|f_date| (f_date.year(), f_date.day())).collect();
+    assert!(years == [(3, 6), (4, 5), (5, 5), (6, 5), (7, 6), (8, 5), (9, 5), (10, 5), (11, 6)]);
+
+    let start = JewishDate::new(5784, 6, 1).unwrap();
+    let months: Vec<i32> = DateRange::inclusive(start, start.add_days(Days(100)).unwrap()).by(Step::Months(1))
+        .map(|j_date| j_date.month()).collect();
+    assert!(months == [6, 7, 8, 9]);
+
+    /* The length of a long range is computed without walking it. */
+    let start = GregorianDate::new(-999999, 1, 31).unwrap();
+    let end = GregorianDate::new(999999, 12, 30).unwrap();
+    let range = DateRange::inclusive(start, end).by(Step::Months(1));
+    assert!(range.len() == 1999998 * 12 - 1, "erroneous length: {}", range.len());
+    assert!(range.clone().next_back() == GregorianDate::new(999999, 11, 30).ok());
+    assert!(DateRange::exclusive(start, end).by(Step::Years(7)).len() == 285714);
+
+    let start = JewishDate::new(3, 7, 29).unwrap();
+    for years in [1, 2, 19, 100] {
+        let end = start.add_years(years, ClampPolicy::Clamp).unwrap();
+        for n in 1..15 {
+            let range = DateRange::inclusive(start, end).by(Step::Months(n));
+            let walked = (0..).take_while(|&i| start.add_months(i * n, ClampPolicy::Clamp).unwrap() <= end).count();
+            assert!(range.len() == walked, "{} months by {}   erroneous length: {}", years, n, range.len());
+        }
+    }
+    let start = JewishDate::new(5000, 1, 1).unwrap();
+    assert!(start.add_months(-i32::MAX, ClampPolicy::Clamp) == Err(CalendarError::OutOfRange));
+    assert!(start.add_months(235 * 100, ClampPolicy::Strict) == JewishDate::new(6900, 1, 1));
+}
+
+#[test]
+pub fn test_date_intervals() {
+    println!("Verifying the intervals of dates");
+
+    let year_8 = Interval::<FrenchDate>::year(8).unwrap();
+    assert!(year_8.length() == Days(365) && year_8.days().count() == 365);
+    assert!(year_8.end() == FrenchDate::new(8, 13, 5).unwrap());
+    assert!(Interval::<FrenchDate>::year(7).unwrap().length() == Days(366));
+    assert!(Interval::<GregorianDate>::year(2000).unwrap().length() == Days(366));
+    assert!(Interval::<JewishDate>::year(5784).unwrap().length() == Days(383));
+
+    let decades: Vec<FrenchDate> = Interval::<FrenchDate>::month(2, 7).unwrap().days().by(Step::Decades(1)).collect();
+    assert!(decades == [
+        FrenchDate::new(2, 7, 1).unwrap(),
+        FrenchDate::new(2, 7, 11).unwrap(),
+        FrenchDate::new(2, 7, 21).unwrap(),
+    ]);
+    assert!(Interval::<FrenchDate>::month(2, 13).unwrap().length() == Days(5));
+    assert!(Interval::<FrenchDate>::month(2, 14).is_err());
+
+    let brumaire = Interval::<FrenchDate>::month(8, 2).unwrap();
+    let coup = Interval::new(FrenchDate::new(8, 2, 18).unwrap(), FrenchDate::new(8, 3, 3).unwrap()).unwrap();
+    assert!(brumaire.contains(&FrenchDate::new(8, 2, 30).unwrap()));
+    assert!(!brumaire.contains(&FrenchDate::new(8, 3, 1).unwrap()));
+    assert!(brumaire.overlaps(&coup) && coup.overlaps(&brumaire));
+    let common = brumaire.intersection(&coup).unwrap();
+    assert!(common.start() == coup.start() && common.end() == brumaire.end());
+    assert!(common.length() == Days(13));
+
+    let frimaire = Interval::<FrenchDate>::month(8, 3).unwrap();
+    assert!(!brumaire.overlaps(&frimaire));
+    assert!(brumaire.intersection(&frimaire).is_none());
+    assert!(Interval::new(coup.end(), coup.start()).is_none());
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "std")]
+
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Command, Stdio};
+
+/* Run repub with the arguments and the standard input, returning the exit
+ * code and the standard output. */
+fn repub(args: &[&str], input: &str) -> (i32, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_repub"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    (output.status.code().unwrap(), String::from_utf8(output.stdout).unwrap())
+}
+
+#[test]
+pub fn test_repub_conversions() {
+    println!("Verifying the conversions of the repub command");
+
+    let check = |args: &[&str], input: &str, expected: (i32, &str)| {
+        let (code, output) = repub(args, input);
+        assert!((code, output.as_str()) == expected, "{:?} {:?}   erroneous: {} {:?}", args, input, code, output);
+    };
+
+    check(&["to-french", "1799-11-09"], "", (0, "Octidi 18 Brumaire an VIII\n"));
+    check(&["to-french", "--format", "%d %B an %y", "1799-11-09"], "", (0, "18 Brumaire an 8\n"));
+    check(&["to-gregorian", "18 brumaire VIII"], "", (0, "1799-11-09\n"));
+    check(&["to-gregorian", "--format=%A %-d %B %Y", "9 thermidor an II"], "", (0, "Sunday 27 July 1794\n"));
+    check(&["to-gregorian"], "18 brumaire VIII\n\n1er vendémiaire an I\n", (0, "1799-11-09\n1792-09-22\n"));
+    check(&["to-french", "--json", "1799-11-09"], "",
+          (0, "{\"input\": \"1799-11-09\", \"french\": {\"year\": 8, \"month\": 2, \"day\": 18}, \
+               \"text\": \"Octidi 18 Brumaire an VIII\"}\n"));
+    check(&["info", "18 brumaire VIII"], "",
+          (0, "Gregorian:   Saturday 9 November 1799\nRepublican:  Octidi 18 Brumaire an VIII\n\
+               Weekday:     Saturday\nDécade day:  Octidi\nRural name:  Dentelaire\nSDN:         2378444\n"));
+    check(&["info", "--json", "1795-09-22"], "",
+          (0, "{\"input\": \"1795-09-22\", \"gregorian\": {\"year\": 1795, \"month\": 9, \"day\": 22}, \
+               \"french\": {\"year\": 3, \"month\": 13, \"day\": 6}, \"weekday\": \"Tuesday\", \
+               \"decade_day\": null, \"rural_name\": \"Jour de la Révolution\", \"sdn\": 2376935}\n"));
+}
+
+#[test]
+pub fn test_repub_round_trip() {
+    println!("Verifying that the output of repub to-french is read back by to-gregorian");
+
+    let dates = "1792-09-22\n1794-07-27\n1795-09-22\n1799-11-09\n1871-03-18\n1999-12-31\n2026-10-18\n\
+                 5791-09-21\n";
+    let (code, french) = repub(&["to-french"], dates);
+    assert!(code == 0, "erroneous exit code: {}", code);
+    let (code, gregorian) = repub(&["to-gregorian"], &french);
+    assert!(code == 0 && gregorian == dates, "{:?}   erroneous: {} {:?}", french, code, gregorian);
+
+    let (code, info) = repub(&["info", "--json"], &french);
+    assert!(code == 0 && info.lines().count() == dates.lines().count(), "erroneous: {} {:?}", code, info);
+    assert!(info.contains("\"gregorian\": {\"year\": 2026, \"month\": 10, \"day\": 18}"));
+}
+
+#[test]
+pub fn test_repub_errors() {
+    println!("Verifying the exit codes of the repub command");
+
+    let check = |args: &[&str], input: &str, expected_code: i32| {
+        let (code, _) = repub(args, input);
+        assert!(code == expected_code, "{:?} {:?}   erroneous exit code: {}", args, input, code);
+    };
+
+    check(&["to-french", "1900-02-29"], "", 1);
+    check(&["to-french", "1799/11/09"], "", 1);
+    check(&["to-gregorian", "31 brumaire VIII"], "", 1);
+    check(&["to-french", "1700-01-01"], "", 3);
+    check(&["to-french", "-0043-03-15"], "", 3);
+    check(&["to-french", "99999999999-01-01"], "", 3);
+    check(&["to-gregorian", "1 vendémiaire an 0"], "", 1);
+    check(&[], "", 2);
+    check(&["to-roman", "1799-11-09"], "", 2);
+    check(&["to-french", "--verbose", "1799-11-09"], "", 2);
+    check(&["info", "--format", "%d", "1799-11-09"], "", 2);
+    check(&["--help"], "", 0);
+
+    /* The first failure gives the exit code, the other dates are still
+     * converted. */
+    let (code, output) = repub(&["to-french", "--json"], "1700-01-01\n1799-11-09\n1900-02-29\n");
+    assert!(code == 3, "erroneous exit code: {}", code);
+    assert!(output.lines().count() == 3 && output.lines().nth(1).unwrap().contains("Brumaire"),
+            "erroneous output: {:?}", output);
+    assert!(output.starts_with("{\"input\": \"1700-01-01\", \"error\": \"date out of the supported range\"}"),
+            "erroneous output: {:?}", output);
+}
+
+#[test]
+pub fn test_repub_stdin() {
+    println!("Verifying that repub converts its standard input line by line");
+
+    /* The first date is converted before the second one is written. */
+    let mut child = Command::new(env!("CARGO_BIN_EXE_repub"))
+        .arg("to-gregorian")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+    let mut line = String::new();
+    stdin.write_all(b"18 brumaire VIII\n").unwrap();
+    stdout.read_line(&mut line).unwrap();
+    assert!(line == "1799-11-09\n", "erroneous output: {:?}", line);
+    line.clear();
+    stdin.write_all(b"9 thermidor II\n").unwrap();
+    drop(stdin);
+    stdout.read_line(&mut line).unwrap();
+    assert!(line == "1794-07-27\n", "erroneous output: {:?}", line);
+    assert!(child.wait().unwrap().code() == Some(0));
+
+    /* An input that is not UTF-8 cannot be read. */
+    let mut child = Command::new(env!("CARGO_BIN_EXE_repub"))
+        .arg("to-french")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"1799-11-09\n\xff\xfe\n1799-11-10\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.code() == Some(4), "erroneous exit code: {:?}", output.status);
+    assert!(output.stdout == b"Octidi 18 Brumaire an VIII\n", "erroneous output: {:?}", output.stdout);
+}
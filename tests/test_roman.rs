@@ -0,0 +1,51 @@
+#![cfg(feature = "alloc")]
+
+use french_republican_calendar::roman::{self, RomanError, RomanMode};
+
+#[test]
+pub fn test_roman_round_trip() {
+    println!("Verifying the Roman numerals from {} to {}", 1, 3999);
+
+    for number in 1..=3999 {
+        let numerals = roman::to_roman(number).unwrap();
+        let mut written = String::new();
+        roman::write_roman(&mut written, number).unwrap();
+        assert!(written == numerals);
+        assert!(roman::parse_roman(&numerals, RomanMode::Strict) == Ok(number), "{} {}", number, numerals);
+        assert!(roman::parse_roman(&numerals.to_lowercase(), RomanMode::Strict) == Ok(number));
+        assert!(roman::parse_roman(&numerals, RomanMode::Lenient) == Ok(number));
+    }
+}
+
+#[test]
+pub fn test_roman_modes() {
+    println!("Verifying the strict and lenient Roman numerals");
+
+    let check = |numerals, strict, lenient| {
+        assert!(roman::parse_roman(numerals, RomanMode::Strict) == strict, "{} strict", numerals);
+        assert!(roman::parse_roman(numerals, RomanMode::Lenient) == lenient, "{} lenient", numerals);
+    };
+
+    check("XIV", Ok(14), Ok(14));
+    check("IIII", Err(RomanError::NotCanonical), Ok(4));
+    check("VIIII", Err(RomanError::NotCanonical), Ok(9));
+    check("IIX", Err(RomanError::NotCanonical), Ok(8));
+    check("MDCCCCX", Err(RomanError::NotCanonical), Ok(1910));
+    check("IC", Err(RomanError::NotCanonical), Ok(99));
+    check("xiiij", Err(RomanError::InvalidSymbol(4)), Ok(14));
+    check("MMMM", Err(RomanError::OutOfRange), Ok(4000));
+    check("", Err(RomanError::Empty), Err(RomanError::Empty));
+    check("X1V", Err(RomanError::InvalidSymbol(1)), Err(RomanError::InvalidSymbol(1)));
+    check("IIV", Err(RomanError::NotCanonical), Ok(3));
+    check("IIIV", Err(RomanError::NotCanonical), Ok(2));
+    check("IIIIIV", Err(RomanError::OutOfRange), Err(RomanError::OutOfRange));
+
+    assert!(roman::to_roman(1799).as_deref() == Some("MDCCXCIX"));
+    assert!(roman::to_roman(3999).as_deref() == Some("MMMCMXCIX"));
+    assert!(roman::to_roman(0).is_none());
+    assert!(roman::to_roman(4000).is_none());
+    assert!(roman::to_roman(-5).is_none());
+    assert!(roman::write_roman(&mut String::new(), 4000).is_err());
+    assert!(roman::from_roman("an").is_none());
+    assert!(RomanError::InvalidSymbol(3).to_string() == "invalid Roman numeral symbol at 3");
+}
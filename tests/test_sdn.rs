@@ -0,0 +1,104 @@
+use french_republican_calendar::{
+    french_calendar::{self, FrenchDate, LeapRule}, gregorian_calendar::{self, GregorianDate},
+    julian_calendar::JulianDate, CalendarDate, CalendarError, Days, Sdn,
+};
+
+#[test]
+pub fn test_sdn_range() {
+    println!("Verifying the Gregorian and Julian dates over the supported range");
+
+    /* Check every 997th SDN and the following day. */
+    let mut sdn = Sdn::MIN;
+    while let Ok(next) = sdn.checked_add(Days(1)) {
+        if let Ok(g_date) = sdn.to_date::<GregorianDate>() {
+            assert!(Sdn::from_date(&g_date) == Ok(sdn), "{:?} {:?}", sdn, g_date);
+            let mut g_date2 = g_date;
+            g_date2.next_day();
+            assert!(next.to_date() == Ok(g_date2), "{:?} {:?}", next, g_date2);
+        }
+        let j_date: JulianDate = sdn.to_date().unwrap();
+        assert!(Sdn::from_date(&j_date) == Ok(sdn), "{:?} {:?}", sdn, j_date);
+        let mut j_date2 = j_date;
+        j_date2.next_day();
+        assert!(next.to_date() == Ok(j_date2), "{:?} {:?}", next, j_date2);
+        sdn = match sdn.checked_add(Days(997)) {
+            Ok(sdn) => sdn,
+            Err(_) => break,
+        };
+    }
+
+    println!("Verifying the limits of the supported range");
+
+    let first = Sdn::from_date(&GregorianDate::new(-1000000, 1, 1).unwrap()).unwrap();
+    let last = Sdn::from_date(&GregorianDate::new(1000000, 12, 31).unwrap()).unwrap();
+    assert!(GregorianDate::valid_range() == (first..=last));
+    assert!(JulianDate::valid_range() == (Sdn::MIN..=Sdn::MAX));
+    assert!(Sdn::from_date(&JulianDate::new(-1000000, 1, 1).unwrap()) == Ok(Sdn::MIN));
+    assert!(Sdn::from_date(&JulianDate::new(1000000, 12, 31).unwrap()) == Ok(Sdn::MAX));
+    assert!((first - Days(1)).to_date::<GregorianDate>() == Err(CalendarError::OutOfRange));
+    assert!((last + Days(1)).to_date::<GregorianDate>() == Err(CalendarError::OutOfRange));
+    assert!(JulianDate::new(1000001, 1, 1) == Err(CalendarError::OutOfRange));
+
+    /* 2000000 years from 1000000 B.C. to 1000000 A.D., without year zero,
+     * that is 5000 cycles of 400 years. */
+    assert!(first.days_until(last) == Days(5000 * 146097 - 1));
+}
+
+#[test]
+pub fn test_sdn_negative() {
+    println!("Verifying the SDNs before November 25, 4714 B.C.");
+
+    let sdn = Sdn::new(0).unwrap();
+    assert!(sdn.to_date() == GregorianDate::new(-4714, 11, 24));
+    assert!(sdn.to_date() == JulianDate::new(-4713, 1, 1));
+    assert!(sdn.to_date::<FrenchDate>() == Err(CalendarError::OutOfRange));
+    assert!(sdn.day_of_week() == gregorian_calendar::day_of_week(7));
+
+    let sdn = Sdn::new(-1).unwrap();
+    assert!(sdn.to_date() == GregorianDate::new(-4714, 11, 23));
+    assert!(sdn.day_of_week() == 0);
+
+    /* The Ides of March of 44 B.C. */
+    let j_date = JulianDate::new(-44, 3, 15).unwrap();
+    let sdn = Sdn::from_date(&j_date).unwrap();
+    assert!(sdn.value() == 1705426, "{:?}", sdn);
+
+    let g_date = GregorianDate::new(-10001, 3, 1).unwrap();
+    let sdn = Sdn::from_date(&g_date).unwrap();
+    assert!(sdn.value() < 0);
+    assert!(sdn.checked_sub(Days(1)).unwrap().to_date() == GregorianDate::new(-10001, 2, 29));
+    assert!(GregorianDate::from_sdn(sdn) == Ok(g_date));
+    assert!(gregorian_calendar::try_gregorian_to_sdn(&g_date) == Ok(sdn));
+}
+
+#[test]
+pub fn test_sdn_arithmetic() {
+    println!("Verifying the checked arithmetic on SDNs");
+
+    assert!(Sdn::new(Sdn::MIN.value() - 1) == Err(CalendarError::OutOfRange));
+    assert!(Sdn::try_from(Sdn::MAX.value() + 1) == Err(CalendarError::OutOfRange));
+    assert!(Sdn::try_from(i64::MAX) == Err(CalendarError::OutOfRange));
+    assert!(Sdn::MAX.checked_add(Days(1)) == Err(CalendarError::OutOfRange));
+    assert!(Sdn::MIN.checked_sub(Days(1)) == Err(CalendarError::OutOfRange));
+    assert!(Sdn::MIN.checked_add(Days(i32::MAX)) == Err(CalendarError::OutOfRange));
+    assert!(Sdn::MAX.checked_sub(Days(i32::MIN)) == Err(CalendarError::OutOfRange));
+    assert!(Sdn::MAX.checked_add(Days(-730499999)) == Ok(Sdn::MIN));
+
+    assert!(Sdn::MIN.days_until(Sdn::MAX) == Days(730499999));
+    assert!(Sdn::MAX - Sdn::MIN == Days(730499999));
+    assert!(Sdn::MIN + Days(730499999) == Sdn::MAX);
+    assert!(i64::from(Sdn::new(2451604).unwrap() - Days(4)) == 2451600);
+
+    let g_date = GregorianDate::new(1799, 11, 9).unwrap();
+    let sdn = Sdn::from_date(&g_date).unwrap();
+    assert!(sdn.value() == gregorian_calendar::gregorian_to_sdn(&g_date) as i64);
+    assert!(sdn.to_date() == FrenchDate::new(8, 2, 18));
+    assert!(sdn.day_of_week() == 6);
+
+    let f_date = FrenchDate::from_sdn(Sdn::MAX).unwrap();
+    assert!(Sdn::from_date(&f_date) == Ok(Sdn::MAX));
+    let f_date = FrenchDate::new(f_date.year() + 1, 1, 1).unwrap();
+    assert!(f_date.to_sdn() == Err(CalendarError::OutOfRange));
+    assert!(french_calendar::french_to_sdn_with_rule(&f_date, LeapRule::Romme) == 0);
+    assert!(!french_calendar::sdn_to_french_with_rule(i32::MAX, LeapRule::Romme).is_valid());
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+
+use french_republican_calendar::{
+    french_calendar::FrenchDate, gregorian_calendar::GregorianDate, jewish_calendar::JewishDate,
+    julian_calendar::JulianDate,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Event {
+    name: String,
+    #[serde(with = "french_republican_calendar::serialization::compact")]
+    republican: FrenchDate,
+    #[serde(with = "french_republican_calendar::serialization::compact")]
+    gregorian: GregorianDate,
+    julian: JulianDate,
+}
+
+#[test]
+pub fn test_serde_struct() {
+    println!("Verifying the serialization of dates as structures");
+
+    let f_date = FrenchDate::new(8, 2, 18).unwrap();
+    let json = serde_json::to_string(&f_date).unwrap();
+    assert!(json == r#"{"year":8,"month":2,"day":18}"#, "{}", json);
+    assert!(serde_json::from_str::<FrenchDate>(&json).unwrap() == f_date);
+    assert!(serde_json::from_str::<FrenchDate>("[8,2,18]").unwrap() == f_date);
+    assert!(serde_json::from_str::<FrenchDate>(r#"{"day":18,"year":8,"month":2}"#).unwrap() == f_date);
+
+    let h_date = JewishDate::new(5560, 2, 11).unwrap();
+    let json = serde_json::to_string(&h_date).unwrap();
+    assert!(serde_json::from_str::<JewishDate>(&json).unwrap() == h_date);
+
+    println!("Verifying the validation of deserialized dates");
+
+    assert!(serde_json::from_str::<FrenchDate>(r#"{"year":4,"month":13,"day":6}"#).is_err());
+    assert!(serde_json::from_str::<FrenchDate>(r#"{"year":3,"month":13,"day":6}"#).is_ok());
+    assert!(serde_json::from_str::<GregorianDate>(r#"{"year":1900,"month":2,"day":29}"#).is_err());
+    assert!(serde_json::from_str::<GregorianDate>(r#"{"year":0,"month":1,"day":1}"#).is_err());
+    assert!(serde_json::from_str::<GregorianDate>(r#"{"year":1900,"month":2}"#).is_err());
+    assert!(serde_json::from_str::<GregorianDate>(r#"{"year":1900,"month":2,"day":1,"hour":3}"#).is_err());
+    assert!(serde_json::from_str::<JewishDate>(r#"{"year":5785,"month":7,"day":1}"#).is_err());
+    assert!(serde_json::from_str::<JulianDate>("[1799,10]").is_err());
+}
+
+#[test]
+pub fn test_serde_compact() {
+    println!("Verifying the serialization of dates as compact strings");
+
+    let event = Event {
+        name: "18 Brumaire".to_string(),
+        republican: FrenchDate::new(8, 2, 18).unwrap(),
+        gregorian: GregorianDate::new(1799, 11, 9).unwrap(),
+        julian: JulianDate::new(1799, 10, 29).unwrap(),
+    };
+    let json = serde_json::to_string(&event).unwrap();
+    assert!(
+        json == r#"{"name":"18 Brumaire","republican":"0008-02-18","gregorian":"1799-11-09","julian":{"year":1799,"month":10,"day":29}}"#,
+        "{}", json
+    );
+    assert!(serde_json::from_str::<Event>(&json).unwrap() == event);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Compact<T: french_republican_calendar::CalendarDate>(
+        #[serde(with = "french_republican_calendar::serialization::compact")] T,
+    );
+
+    let check = |date: GregorianDate, text: &str| {
+        let json = serde_json::to_string(&Compact(date)).unwrap();
+        assert!(json == format!("\"{}\"", text), "{}", json);
+        assert!(serde_json::from_str::<Compact<GregorianDate>>(&json).unwrap() == Compact(date));
+    };
+    check(GregorianDate::new(-44, 3, 15).unwrap(), "-0043-03-15");
+    check(GregorianDate::new(-1, 12, 31).unwrap(), "0000-12-31");
+    check(GregorianDate::new(1, 1, 1).unwrap(), "0001-01-01");
+    check(GregorianDate::new(12345, 6, 7).unwrap(), "+12345-06-07");
+
+    let json = serde_json::to_string(&Compact(FrenchDate::new(3, 13, 6).unwrap())).unwrap();
+    assert!(json == r#""0003-13-06""#, "{}", json);
+
+    println!("Verifying the validation of compact strings");
+
+    for text in ["0004-13-06", "1799-11", "1799-11-09-01", "1799/11/09", "99-11-09", "1799-1-09", ""] {
+        let json = format!("\"{}\"", text);
+        assert!(serde_json::from_str::<Compact<FrenchDate>>(&json).is_err(), "{}", text);
+    }
+    assert!(serde_json::from_str::<Compact<GregorianDate>>(r#""1900-02-29""#).is_err());
+    assert!(serde_json::from_str::<Compact<GregorianDate>>("18991109").is_err());
+}